@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::path::Path;
 use serde::Deserialize;
-use crate::core::Packet;
+use crate::core::Schema;
 use crate::utils::{MorphError, MorphResult};
 
 #[derive(Debug)]
@@ -53,6 +53,8 @@ impl GenerationConfig {
 
 
 pub trait Generator {
-    fn generate(&self, packets: &Vec<Packet>) -> MorphResult<()>;
+    fn generate(&self, schema: &Schema, context: &Schema) -> MorphResult<()>;
+    fn generate_constants(&self, schema: &Schema) -> MorphResult<()>;
+    fn generate_states(&self, schema: &Schema) -> MorphResult<()>;
 
 }
@@ -1,9 +1,10 @@
-use crate::core::{FieldType, GenerationError, Generator, Packet};
+use crate::core::{find_attribute, Attribute, Constant, Constraint, ConstraintKind, Direction, Enum, EnumKind, Field, FieldType, GenerationError, Generator, Literal, Packet, PacketKind, Schema, Service, Union};
 use crate::utils::MorphResult::{Errors, Success};
-use crate::utils::{capitalize, hash_str_to_i8_positive, MorphResult};
+use crate::utils::{capitalize, MorphResult};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fmt::Display;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Deserialize)]
 pub struct JavaOptions {
@@ -23,6 +24,15 @@ pub struct JavaOptions {
     #[serde(default = "default_true")]
     pub generate_hashcode: bool,
 
+    #[serde(default)]
+    pub optional_getters: bool,
+
+    #[serde(default = "default_true")]
+    pub flatten_inheritance: bool,
+
+    #[serde(default)]
+    pub drop_set_duplicates: bool,
+
 }
 
 fn default_true() -> bool { true }
@@ -31,6 +41,116 @@ pub struct JavaGenerator {
     pub options: JavaOptions,
 }
 
+struct JavaScope<'a> {
+    schema: &'a Schema,
+    type_names: HashMap<String, String>,
+    versioned_types: Vec<String>,
+}
+
+impl JavaScope<'_> {
+
+    fn type_name(&self, qualified_name: &str) -> String {
+        match self.type_names.get(qualified_name) {
+            Some(type_name) => type_name.clone(),
+            None => java_simple_name(self.schema, qualified_name),
+        }
+    }
+
+    fn version_arg(&self, type_name: &str) -> &str {
+        match self.versioned_types.iter().any(|versioned| versioned == type_name) {
+            true => ", protocolVersion",
+            false => "",
+        }
+    }
+
+}
+
+fn java_name(attributes: &[Attribute]) -> Option<String> {
+    match find_attribute(attributes, "java").and_then(|attribute| attribute.arg("name")) {
+        Some(Literal::Str(name)) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+fn java_simple_name(schema: &Schema, qualified_name: &str) -> String {
+    schema.find_packet(qualified_name)
+        .and_then(|packet| java_name(&packet.attributes))
+        .unwrap_or_else(|| simple_name(qualified_name).to_string())
+}
+
+fn packet_class_name(packet: &Packet) -> String {
+    java_name(&packet.attributes).unwrap_or_else(|| packet.name.clone())
+}
+
+fn java_field_name(field: &Field) -> String {
+    java_name(&field.attributes).unwrap_or_else(|| to_camel_case(&field.name))
+}
+
+fn is_deprecated(attributes: &[Attribute]) -> bool {
+    find_attribute(attributes, "deprecated").is_some()
+}
+
+fn schema_version(schema: &Schema, qualified_name: &str) -> Option<u32> {
+    match schema.find_packet(qualified_name) {
+        Some(packet) => packet.version,
+        None => schema.unions.iter()
+            .find(|union| union.qualified_name() == qualified_name)
+            .and_then(|union| union.version),
+    }
+}
+
+fn version_condition(attributes: &[Attribute]) -> Option<String> {
+
+    let mut conditions: Vec<String> = Vec::new();
+
+    if let Some(Literal::Integer(since)) = find_attribute(attributes, "since").and_then(|since| since.positional_arg(0)) {
+        conditions.push(format!("protocolVersion >= {}", since));
+    }
+
+    if let Some(Literal::Integer(until)) = find_attribute(attributes, "until").and_then(|until| until.positional_arg(0)) {
+        conditions.push(format!("protocolVersion < {}", until));
+    }
+
+    match conditions.is_empty() {
+        true => None,
+        false => Some(conditions.join(" && ")),
+    }
+}
+
+fn simple_name(qualified_name: &str) -> &str {
+    match qualified_name.rsplit_once('.') {
+        Some((_, name)) => name,
+        None => qualified_name,
+    }
+}
+
+fn namespace_of(qualified_name: &str) -> Option<String> {
+    qualified_name.rsplit_once('.').map(|(namespace, _)| namespace.to_string())
+}
+
+fn referenced_type_names(fields: &[Field]) -> Vec<String> {
+
+    fn collect(field_type: &FieldType, names: &mut Vec<String>) {
+        match field_type {
+            FieldType::Nested(name) | FieldType::Union(name) if !names.contains(name) => {
+                names.push(name.clone());
+            }
+            FieldType::Array(inner_type) | FieldType::FixedArray(inner_type, _) | FieldType::Set(inner_type) | FieldType::Optional(inner_type) => collect(inner_type, names),
+            FieldType::Map(key_type, value_type) => {
+                collect(key_type, names);
+                collect(value_type, names);
+            }
+            _ => {}
+        }
+    }
+
+    let mut names: Vec<String> = Vec::new();
+    for field in fields {
+        collect(&field.typ, &mut names);
+    }
+    names
+}
+
 enum JavaType {
     Bool,
     Byte,
@@ -41,8 +161,16 @@ enum JavaType {
     Double,
     Char,
     Str,
+    Bytes,
     Array(Box<JavaType>),
+    FixedArray(Box<JavaType>, u32),
+    Set(Box<JavaType>),
+    Optional(Box<JavaType>),
+    Map(Box<JavaType>, Box<JavaType>),
     Class(String),
+    Struct(String),
+    Enum(String),
+    Union(String),
 }
 
 impl JavaType {
@@ -73,7 +201,8 @@ impl Display for JavaType {
             JavaType::Double => write!(f, "double"),
             JavaType::Char => write!(f, "char"),
             JavaType::Str => write!(f, "String"),
-            JavaType::Array(inner_type) => {
+            JavaType::Bytes => write!(f, "byte[]"),
+            JavaType::Array(inner_type) | JavaType::FixedArray(inner_type, _) => {
                 if inner_type.is_primitive() {
 
                     match convert_primitive_to_wrapper(inner_type) {
@@ -86,12 +215,20 @@ impl Display for JavaType {
 
                 }
             }
+            JavaType::Set(inner_type) => write!(f, "Set<{}>", primitive_or_wrapper_string(inner_type)),
+            JavaType::Optional(inner_type) => write!(f, "{}", primitive_or_wrapper_string(inner_type)),
+            JavaType::Map(key_type, value_type) => {
+                write!(f, "Map<{}, {}>", primitive_or_wrapper_string(key_type), primitive_or_wrapper_string(value_type))
+            }
             JavaType::Class(class_name) => write!(f, "{}", class_name),
+            JavaType::Struct(struct_name) => write!(f, "{}", struct_name),
+            JavaType::Enum(enum_name) => write!(f, "{}", enum_name),
+            JavaType::Union(union_name) => write!(f, "{}", union_name),
         }
     }
 }
 
-fn convert_to_java_type(field_type: &FieldType) -> JavaType {
+fn convert_to_java_type(field_type: &FieldType, scope: &JavaScope) -> JavaType {
 
     match field_type {
 
@@ -104,12 +241,45 @@ fn convert_to_java_type(field_type: &FieldType) -> JavaType {
         FieldType::F64 => JavaType::Double,
         FieldType::Char => JavaType::Char,
         FieldType::Str => JavaType::Str,
+        FieldType::Bytes => JavaType::Bytes,
         FieldType::Array(array_type) => {
             JavaType::Array(
-                Box::new(convert_to_java_type(&**array_type))
+                Box::new(convert_to_java_type(array_type, scope))
+            )
+        },
+        FieldType::FixedArray(array_type, size) => {
+            JavaType::FixedArray(
+                Box::new(convert_to_java_type(array_type, scope)),
+                size.value().unwrap_or_default()
             )
         },
-        FieldType::Nested(class_name) => JavaType::Class(class_name.clone())
+        FieldType::Set(inner_type) => {
+            JavaType::Set(
+                Box::new(convert_to_java_type(inner_type, scope))
+            )
+        },
+        FieldType::Optional(inner_type) => {
+            JavaType::Optional(
+                Box::new(convert_to_java_type(inner_type, scope))
+            )
+        },
+        FieldType::Map(key_type, value_type) => {
+            JavaType::Map(
+                Box::new(convert_to_java_type(key_type, scope)),
+                Box::new(convert_to_java_type(value_type, scope))
+            )
+        },
+        FieldType::Nested(class_name) => {
+            if scope.schema.find_enum(class_name).is_some() {
+                JavaType::Enum(scope.type_name(class_name))
+            } else if scope.schema.find_packet(class_name).is_some_and(|p| p.kind == PacketKind::Struct) {
+                JavaType::Struct(scope.type_name(class_name))
+            } else {
+                JavaType::Class(scope.type_name(class_name))
+            }
+        }
+        FieldType::Union(union_name) => JavaType::Union(scope.type_name(union_name)),
+        FieldType::Generic(class_name, _) => JavaType::Struct(scope.type_name(class_name)),
 
     }
 
@@ -169,6 +339,152 @@ fn primitive_or_wrapper_string(field_type: &JavaType) -> String {
 
 }
 
+fn java_literal(literal: &Literal, java_type: &JavaType) -> String {
+
+    if let JavaType::Optional(inner_type) = java_type {
+        return java_literal(literal, inner_type);
+    }
+
+    match (literal, java_type) {
+        (Literal::Integer(value), JavaType::Byte) => format!("(byte) {}", value),
+        (Literal::Integer(value), JavaType::Short) => format!("(short) {}", value),
+        (Literal::Integer(value), JavaType::Int) if *value > i32::MAX as i64 => format!("(int) {}L", value),
+        (Literal::Integer(value), JavaType::Long) => format!("{}L", value),
+        (Literal::Integer(value), JavaType::Float) => format!("{}f", value),
+        (Literal::Integer(value), JavaType::Double) => format!("{}.0", value),
+        (Literal::Float(value), JavaType::Float) => format!("{:?}f", value),
+        (Literal::Float(value), _) => format!("{:?}", value),
+        (Literal::Str(value), JavaType::Char) => format!("'{}'", escape_java(value, '\'')),
+        (Literal::Str(value), _) => format!("\"{}\"", escape_java(value, '"')),
+        (Literal::Reference(name), JavaType::Enum(enum_name)) => format!("{}.{}", enum_name, name),
+        (literal, _) => literal.to_string(),
+    }
+
+}
+
+fn missing_value(field: &Field, java_type: &JavaType) -> String {
+
+    if let Some(default) = &field.default {
+        return java_literal(default, java_type);
+    }
+
+    match java_type {
+        JavaType::Bool => "false".to_string(),
+        JavaType::Byte | JavaType::Short | JavaType::Int | JavaType::Long |
+        JavaType::Float | JavaType::Double | JavaType::Char => "0".to_string(),
+        JavaType::Bytes => "new byte[0]".to_string(),
+        JavaType::Array(_) | JavaType::FixedArray(_, _) => "new ArrayList<>()".to_string(),
+        JavaType::Set(_) => "new LinkedHashSet<>()".to_string(),
+        JavaType::Map(_, _) => "new LinkedHashMap<>()".to_string(),
+        _ => "null".to_string(),
+    }
+}
+
+fn escape_java(value: &str, quote: char) -> String {
+
+    let mut result = String::new();
+
+    for ch in value.chars() {
+        match ch {
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            _ if ch == quote => {
+                result.push('\\');
+                result.push(ch);
+            }
+            _ => result.push(ch),
+        }
+    }
+
+    result
+}
+
+fn is_bytes(field_type: &FieldType) -> bool {
+    match field_type {
+        FieldType::Bytes => true,
+        FieldType::Optional(inner_type) => is_bytes(inner_type),
+        _ => false,
+    }
+}
+
+fn to_string_expression(field_type: &FieldType, name: &str) -> String {
+    if is_bytes(field_type) {
+        format!("Arrays.toString({})", name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn hash_expression(field_type: &FieldType, name: &str) -> String {
+    if is_bytes(field_type) {
+        format!("Arrays.hashCode({})", name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn numeric_comparison(field_type: &FieldType, name: &str, operator: &str, bound: &Literal) -> String {
+    match field_type {
+        FieldType::U8 => format!("Byte.toUnsignedInt({}) {} {}", name, operator, bound),
+        FieldType::U16 => format!("Short.toUnsignedInt({}) {} {}", name, operator, bound),
+        FieldType::U32 => format!("Integer.toUnsignedLong({}) {} {}L", name, operator, bound),
+        FieldType::U64 => format!("Long.compareUnsigned({}, {}L) {} 0", name, bound, operator),
+        FieldType::I64 => format!("{} {} {}L", name, operator, bound),
+        _ => format!("{} {} {}", name, operator, bound),
+    }
+}
+
+fn numeric_expression(field_type: &FieldType, name: &str) -> String {
+    match field_type {
+        FieldType::U8 => format!("Byte.toUnsignedInt({})", name),
+        FieldType::U16 => format!("Short.toUnsignedInt({})", name),
+        FieldType::U32 => format!("Integer.toUnsignedString({})", name),
+        FieldType::U64 => format!("Long.toUnsignedString({})", name),
+        _ => name.to_string(),
+    }
+}
+
+fn constraint_check(field_type: &FieldType, constraint: &Constraint, name: &str) -> (String, String) {
+
+    let length = match field_type {
+        FieldType::Bytes => format!("{}.length", name),
+        FieldType::Str => format!("{}.length()", name),
+        _ => format!("{}.size()", name),
+    };
+
+    let bound = &constraint.value;
+
+    match constraint.kind {
+        ConstraintKind::Min => (
+            numeric_comparison(field_type, name, "<", bound),
+            format!("must be at least {}, actual value is \" + {}", bound, numeric_expression(field_type, name)),
+        ),
+        ConstraintKind::Max => (
+            numeric_comparison(field_type, name, ">", bound),
+            format!("must be at most {}, actual value is \" + {}", bound, numeric_expression(field_type, name)),
+        ),
+        ConstraintKind::MinLen => (
+            format!("{} < {}", length, bound),
+            format!("must have a length of at least {}, actual length is \" + {}", bound, length),
+        ),
+        ConstraintKind::MaxLen => (
+            format!("{} > {}", length, bound),
+            format!("must have a length of at most {}, actual length is \" + {}", bound, length),
+        ),
+        ConstraintKind::MinItems => (
+            format!("{} < {}", length, bound),
+            format!("must contain at least {} items, actual count is \" + {}", bound, length),
+        ),
+        ConstraintKind::MaxItems => (
+            format!("{} > {}", length, bound),
+            format!("must contain at most {} items, actual count is \" + {}", bound, length),
+        ),
+    }
+
+}
+
 fn to_camel_case(s: &str) -> String {
     let mut result = String::new();
     let mut capitalize_next = false;
@@ -191,25 +507,58 @@ fn to_camel_case(s: &str) -> String {
 
 impl JavaGenerator {
 
-    fn generate_class(&self, packet: &Packet) -> String {
+    fn generate_class(&self, packet: &Packet, schema: &Schema) -> String {
+
+        let flattened = Packet { fields: schema.all_fields(packet), ..packet.clone() };
+        let (declared, parent) = if self.options.flatten_inheritance {
+            (&flattened, None)
+        } else {
+            (packet, packet.parent.as_ref().and_then(|parent| schema.find_packet(parent)))
+        };
+        let packet = &flattened;
 
         let mut content = String::new();
+        let scope = self.create_scope(&packet.qualified_name(), &[packet_class_name(packet)], &packet.fields, schema);
+        let scope = &scope;
 
-        self.write_package(&mut content);
-        self.write_imports(&mut content);
-        if self.has_arrays(packet) {
+        self.write_package(&mut content, &packet.namespace);
+        self.write_imports(&mut content, packet);
+        if self.has_arrays(&packet.fields) {
             self.write_import_list(&mut content);
         }
-        self.write_class(&mut content, packet);
-        self.write_packet_id(&mut content, packet);
-        self.write_fields(&mut content, packet);
-        self.write_constructors(&mut content, packet);
-        self.write_packet_id_getter(&mut content);
-        self.write_getters(&mut content, packet);
-        self.write_setters(&mut content, packet);
-        self.write_encode_method(&mut content, packet);
-        self.write_decode_method(&mut content, packet);
-        self.write_registry_registration(&mut content, packet);
+        if self.has_maps(&packet.fields) {
+            self.write_import_map(&mut content);
+        }
+        if self.has_sets(&packet.fields) {
+            self.write_import_set(&mut content);
+        }
+        if self.has_bytes(&packet.fields) {
+            self.write_import_arrays(&mut content);
+        }
+        if self.options.optional_getters && self.has_optionals(&declared.fields) {
+            self.write_import_optional(&mut content);
+        }
+        self.write_type_imports(&mut content, &packet.namespace, &packet.fields, scope);
+        self.write_class(&mut content, packet, parent, schema);
+        if packet.kind == PacketKind::Packet {
+            self.write_packet_id(&mut content, packet, schema);
+        }
+        if let Some(version) = packet.version {
+            content.push_str(format!("\tpublic static final int VERSION = {};\n", version).as_str());
+        }
+        self.write_fields(&mut content, declared, scope);
+        self.write_constructors(&mut content, declared, packet, scope);
+        if packet.kind == PacketKind::Packet {
+            self.write_packet_id_getter(&mut content);
+        }
+        self.write_getters(&mut content, declared, scope);
+        self.write_setters(&mut content, declared, scope);
+        self.write_validators(&mut content, declared, scope);
+        self.write_encode_method(&mut content, packet, scope);
+        self.write_decode_method(&mut content, packet, scope);
+        if packet.kind == PacketKind::Packet && packet.state.is_none() {
+            self.write_registry_registration(&mut content, packet);
+        }
         if self.options.generate_to_string {
             self.write_to_string(&mut content, packet);
         }
@@ -225,10 +574,19 @@ impl JavaGenerator {
 
     }
 
-    fn has_arrays(&self, packet: &Packet) -> bool {
+    fn has_arrays(&self, fields: &[Field]) -> bool {
 
-        for field in &packet.fields {
-            if let FieldType::Array(_) = &field.typ {
+        fn contains_array(field_type: &FieldType) -> bool {
+            match field_type {
+                FieldType::Array(_) | FieldType::FixedArray(_, _) => true,
+                FieldType::Set(inner_type) | FieldType::Optional(inner_type) => contains_array(inner_type),
+                FieldType::Map(_, value_type) => contains_array(value_type),
+                _ => false,
+            }
+        }
+
+        for field in fields {
+            if contains_array(&field.typ) {
                 return true;
             }
         }
@@ -236,16 +594,121 @@ impl JavaGenerator {
         false
 
     }
+
+    fn has_maps(&self, fields: &[Field]) -> bool {
+
+        fn contains_map(field_type: &FieldType) -> bool {
+            match field_type {
+                FieldType::Map(_, _) => true,
+                FieldType::Array(inner_type) | FieldType::FixedArray(inner_type, _) | FieldType::Optional(inner_type) => contains_map(inner_type),
+                _ => false,
+            }
+        }
+
+        fields.iter().any(|field| contains_map(&field.typ))
+    }
+
+    fn has_sets(&self, fields: &[Field]) -> bool {
+
+        fn contains_set(field_type: &FieldType) -> bool {
+            match field_type {
+                FieldType::Set(_) => true,
+                FieldType::Array(inner_type) | FieldType::FixedArray(inner_type, _) | FieldType::Optional(inner_type) => contains_set(inner_type),
+                FieldType::Map(_, value_type) => contains_set(value_type),
+                _ => false,
+            }
+        }
+
+        fields.iter().any(|field| contains_set(&field.typ))
+    }
+
+    fn has_bytes(&self, fields: &[Field]) -> bool {
+        fields.iter().any(|field| is_bytes(&field.typ))
+    }
+
+    fn has_optionals(&self, fields: &[Field]) -> bool {
+        fields.iter().any(|field| matches!(field.typ, FieldType::Optional(_)))
+    }
     
-    fn write_package(&self, content: &mut String) {
-        content.push_str(format!("package {};\n\n", self.options.package).as_str());
+    fn create_scope<'a>(
+        &self,
+        qualified_name: &str,
+        class_names: &[String],
+        fields: &[Field],
+        schema: &'a Schema
+    ) -> JavaScope<'a> {
+
+        let referenced_names = referenced_type_names(fields);
+        let mut type_names: HashMap<String, String> = HashMap::new();
+
+        for referenced_name in &referenced_names {
+
+            let name = java_simple_name(schema, referenced_name);
+            let clashes_with_class = class_names.contains(&name) && referenced_name != qualified_name;
+            let clashes_with_other = referenced_names.iter()
+                .any(|other| other != referenced_name && java_simple_name(schema, other) == name);
+
+            if clashes_with_class || clashes_with_other {
+                type_names.insert(referenced_name.clone(), self.java_class_name(referenced_name, schema));
+            }
+
+        }
+
+        let mut scope = JavaScope { schema, type_names, versioned_types: Vec::new() };
+
+        if schema_version(schema, qualified_name).is_some() {
+            scope.versioned_types = referenced_names.iter()
+                .filter(|referenced_name| schema_version(schema, referenced_name).is_some())
+                .map(|referenced_name| scope.type_name(referenced_name))
+                .collect();
+        }
+
+        scope
     }
 
-    fn write_imports(&self, content: &mut String) {
-        content.push_str("import me.bottdev.morph.runtime.MorphPacket;\n");
+    fn java_package(&self, namespace: &Option<String>) -> String {
+        match namespace {
+            Some(namespace) => format!("{}.{}", self.options.package, namespace),
+            None => self.options.package.clone(),
+        }
+    }
+
+    fn java_class_name(&self, qualified_name: &str, schema: &Schema) -> String {
+        format!("{}.{}", self.java_package(&namespace_of(qualified_name)), java_simple_name(schema, qualified_name))
+    }
+
+    fn write_package(&self, content: &mut String, namespace: &Option<String>) {
+        content.push_str(format!("package {};\n\n", self.java_package(namespace)).as_str());
+    }
+
+    fn write_type_imports(&self, content: &mut String, namespace: &Option<String>, fields: &[Field], scope: &JavaScope) {
+
+        let imports: Vec<String> = referenced_type_names(fields).into_iter()
+            .filter(|name| namespace_of(name) != *namespace)
+            .filter(|name| scope.type_name(name) == java_simple_name(scope.schema, name))
+            .map(|name| self.java_class_name(&name, scope.schema))
+            .collect();
+
+        if imports.is_empty() {
+            return;
+        }
+
+        content.push('\n');
+        for import in imports {
+            content.push_str(format!("import {};\n", import).as_str());
+        }
+    }
+
+    fn write_imports(&self, content: &mut String, packet: &Packet) {
+        if packet.kind == PacketKind::Packet {
+            content.push_str("import me.bottdev.morph.runtime.MorphPacket;\n");
+        }
         content.push_str("import me.bottdev.morph.runtime.BinaryWriter;\n");
         content.push_str("import me.bottdev.morph.runtime.BinaryReader;\n");
-        content.push_str("import me.bottdev.morph.runtime.PacketRegistries;\n\n");
+        if packet.kind == PacketKind::Packet && packet.state.is_none() {
+            content.push_str("import me.bottdev.morph.runtime.PacketRegistries;\n");
+        }
+        content.push('\n');
         content.push_str("import java.io.ByteArrayOutputStream;\n");
         content.push_str("import java.io.InputStream;\n");
         content.push_str("import java.io.IOException;\n");
@@ -257,13 +720,136 @@ impl JavaGenerator {
         content.push_str("import java.util.ArrayList;\n");
     }
 
-    fn write_class(&self, content: &mut String, packet: &Packet) {
-        content.push_str(format!("\npublic final class {} implements MorphPacket {{\n\n", packet.name).as_str());
+    fn write_import_map(&self, content: &mut String) {
+        content.push_str("import java.util.Map;\n");
+        content.push_str("import java.util.LinkedHashMap;\n");
+    }
+
+    fn write_import_set(&self, content: &mut String) {
+        content.push_str("import java.util.Set;\n");
+        content.push_str("import java.util.LinkedHashSet;\n");
+    }
+
+    fn write_import_arrays(&self, content: &mut String) {
+        content.push_str("import java.util.Arrays;\n");
+    }
+
+    fn write_import_optional(&self, content: &mut String) {
+        content.push_str("import java.util.Optional;\n");
+    }
+
+    fn write_javadoc(&self, content: &mut String, doc: &Option<String>, indent: &str) {
+
+        let Some(doc) = doc else {
+            return;
+        };
+
+        content.push_str(format!("{}/**\n", indent).as_str());
+        for line in doc.lines() {
+            let line = line.replace("*/", "*&#47;");
+            if line.is_empty() {
+                content.push_str(format!("{} *\n", indent).as_str());
+            } else {
+                content.push_str(format!("{} * {}\n", indent, line).as_str());
+            }
+        }
+        content.push_str(format!("{} */\n", indent).as_str());
+    }
+
+    fn attribute_doc(&self, doc: Option<String>, attributes: &[Attribute]) -> Option<String> {
+
+        let mut tags: Vec<String> = Vec::new();
+
+        if let Some(deprecated) = find_attribute(attributes, "deprecated") {
+            match deprecated.positional_arg(0) {
+                Some(Literal::Str(note)) => tags.push(format!("@deprecated {}", note)),
+                _ => tags.push("@deprecated".to_string()),
+            }
+        }
+
+        if let Some(Literal::Integer(version)) = find_attribute(attributes, "since").and_then(|since| since.positional_arg(0)) {
+            tags.push(format!("@since {}", version));
+        }
+
+        if tags.is_empty() {
+            return doc;
+        }
+
+        match doc {
+            Some(doc) => Some(format!("{}\n\n{}", doc, tags.join("\n"))),
+            None => Some(tags.join("\n")),
+        }
+    }
+
+    fn write_deprecated(&self, content: &mut String, attributes: &[Attribute], indent: &str) {
+        if is_deprecated(attributes) {
+            content.push_str(format!("{}@Deprecated\n", indent).as_str());
+        }
+    }
+
+    fn field_doc(&self, field: &Field, scope: &JavaScope) -> Option<String> {
+        self.attribute_doc(self.alias_doc(field, scope), &field.attributes)
+    }
+
+    fn alias_doc(&self, field: &Field, scope: &JavaScope) -> Option<String> {
+
+        let Some(alias_type) = &field.alias_type else {
+            return field.doc.clone();
+        };
+
+        let mut alias_line = format!("Morph type: {{@code {}}}", alias_type);
+
+        if let FieldType::Nested(alias_name) = alias_type {
+            let alias_doc = scope.schema.aliases.iter()
+                .find(|alias| alias.qualified_name() == *alias_name)
+                .and_then(|alias| alias.doc.as_ref());
+            if let Some(alias_doc) = alias_doc {
+                alias_line.push_str(format!("\n\n{}", alias_doc).as_str());
+            }
+        }
+
+        match &field.doc {
+            Some(doc) => Some(format!("{}\n\n{}", doc, alias_line)),
+            None => Some(alias_line),
+        }
+    }
+
+    fn member_modifier(&self) -> &str {
+        if self.options.flatten_inheritance { "private" } else { "protected" }
+    }
+
+    fn write_class(&self, content: &mut String, packet: &Packet, parent: Option<&Packet>, schema: &Schema) {
+        content.push('\n');
+        self.write_javadoc(content, &self.attribute_doc(packet.doc.clone(), &packet.attributes), "");
+        self.write_deprecated(content, &packet.attributes, "");
+
+        let modifiers = if self.options.flatten_inheritance { "public final" } else { "public" };
+        let extends = match parent {
+            Some(parent) if parent.namespace == packet.namespace => format!(" extends {}", packet_class_name(parent)),
+            Some(parent) => format!(" extends {}", self.java_class_name(&parent.qualified_name(), schema)),
+            None => String::new(),
+        };
+
+        match packet.kind {
+            PacketKind::Packet => {
+                content.push_str(format!(
+                    "{} class {}{} implements MorphPacket {{\n\n", modifiers, packet_class_name(packet), extends
+                ).as_str());
+            }
+            PacketKind::Struct => {
+                content.push_str(format!("{} class {}{} {{\n", modifiers, packet_class_name(packet), extends).as_str());
+            }
+        }
     }
 
-    fn write_packet_id(&self, content: &mut String, packet: &Packet) {
-        let hash = hash_str_to_i8_positive(packet.name.as_str());
-        content.push_str(format!("\tpublic static final byte PACKET_ID = {};\n", hash).as_str());
+    fn write_packet_id(&self, content: &mut String, packet: &Packet, schema: &Schema) {
+        let id = if packet.is_auto {
+            schema.reserved.auto_id(packet.qualified_name().as_str()).unwrap_or_default()
+        } else {
+            packet.id
+        };
+        let id = if id > i8::MAX as i32 { format!("(byte) {}", id) } else { id.to_string() };
+        content.push_str(format!("\tpublic static final byte PACKET_ID = {};\n", id).as_str());
     }
 
     fn write_packet_id_getter(&self, content: &mut String) {
@@ -273,17 +859,29 @@ impl JavaGenerator {
         content.push_str("\t}}\n\n");
     }
 
-    fn write_fields(&self, content: &mut String, packet: &Packet) {
+    fn write_fields(&self, content: &mut String, packet: &Packet, scope: &JavaScope) {
         content.push_str("\n");
         for field in &packet.fields {
-            let java_type = convert_to_java_type(&field.typ);
-            let camel_case = to_camel_case(field.name.as_str());
+            let java_type = convert_to_java_type(&field.typ, scope);
+            let camel_case = java_field_name(field);
+
+            self.write_javadoc(content, &self.field_doc(field, scope), "\t");
+            self.write_deprecated(content, &field.attributes, "\t");
+
+            if let FieldType::Array(_) | FieldType::FixedArray(_, _) = &field.typ {
+                content.push_str(format!("\t{} {} {} = new ArrayList<>();\n", self.member_modifier(), java_type, camel_case).as_str());
+
+            } else if let FieldType::Map(_, _) = &field.typ {
+                content.push_str(format!("\t{} {} {} = new LinkedHashMap<>();\n", self.member_modifier(), java_type, camel_case).as_str());
+
+            } else if let FieldType::Set(_) = &field.typ {
+                content.push_str(format!("\t{} {} {} = new LinkedHashSet<>();\n", self.member_modifier(), java_type, camel_case).as_str());
 
-            if let FieldType::Array(_) = &field.typ {
-                content.push_str(format!("\tprivate {} {} = new ArrayList<>();\n", java_type, camel_case).as_str());
+            } else if let FieldType::Bytes = &field.typ {
+                content.push_str(format!("\t{} {} {} = new byte[0];\n", self.member_modifier(), java_type, camel_case).as_str());
 
             } else {
-                content.push_str(format!("\tprivate {} {};\n", java_type, camel_case).as_str());
+                content.push_str(format!("\t{} {} {};\n", self.member_modifier(), java_type, camel_case).as_str());
 
             }
 
@@ -291,43 +889,70 @@ impl JavaGenerator {
         content.push_str("\n");
     }
 
-    fn write_constructors(&self, content: &mut String, packet: &Packet) {
-        self.write_all_args_constructor(content, packet);
+    fn write_constructors(&self, content: &mut String, declared: &Packet, packet: &Packet, scope: &JavaScope) {
+        self.write_all_args_constructor(content, declared, packet, scope);
         if self.options.no_args_constructor {
-            self.write_no_args_constructor(content, packet);
+            self.write_no_args_constructor(content, declared, scope);
         }
     }
 
-    fn write_all_args_constructor(&self, content: &mut String, packet: &Packet) {
-        content.push_str(format!("\tpublic {}(\n", packet.name).as_str());
+    fn write_all_args_constructor(&self, content: &mut String, declared: &Packet, packet: &Packet, scope: &JavaScope) {
+        content.push_str(format!("\tpublic {}(\n", packet_class_name(packet)).as_str());
 
         for (i, field) in packet.fields.iter().enumerate() {
-            let java_type = convert_to_java_type(&field.typ);
-            let camel_case = to_camel_case(&field.name);
+            let java_type = convert_to_java_type(&field.typ, scope);
+            let camel_case = java_field_name(field);
             let comma = if i == packet.fields.len() - 1 { "" } else { "," };
             content.push_str(format!("\t\t{} {}{}\n", java_type, camel_case, comma).as_str());
         }
 
         content.push_str("\t) {\n");
 
-        for field in &packet.fields {
-            let camel_case = to_camel_case(&field.name);
+        let inherited = &packet.fields[..packet.fields.len() - declared.fields.len()];
+        if !inherited.is_empty() {
+            let names: Vec<String> = inherited.iter().map(java_field_name).collect();
+            content.push_str(format!("\t\tsuper({});\n", names.join(", ")).as_str());
+        }
+
+        for field in declared.fields.iter().filter(|field| !field.constraints.is_empty()) {
+            let camel_case = java_field_name(field);
+            content.push_str(format!("\t\tvalidate{}({});\n", capitalize(camel_case.as_str()), camel_case).as_str());
+        }
+
+        for field in &declared.fields {
+            let camel_case = java_field_name(field);
             content.push_str(format!("\t\tthis.{} = {};\n", camel_case, camel_case).as_str());
         }
 
         content.push_str("\t}\n\n");
     }
 
-    fn write_no_args_constructor(&self, content: &mut String, packet: &Packet) {
-        content.push_str(format!("\tpublic {}() {{}}\n\n", packet.name).as_str());
+    fn write_no_args_constructor(&self, content: &mut String, packet: &Packet, scope: &JavaScope) {
+
+        if packet.fields.iter().all(|field| field.default.is_none()) {
+            content.push_str(format!("\tpublic {}() {{}}\n\n", packet_class_name(packet)).as_str());
+            return;
+        }
+
+        content.push_str(format!("\tpublic {}() {{\n", packet_class_name(packet)).as_str());
+
+        for field in &packet.fields {
+            if let Some(default) = &field.default {
+                let java_type = convert_to_java_type(&field.typ, scope);
+                let camel_case = java_field_name(field);
+                content.push_str(format!("\t\tthis.{} = {};\n", camel_case, java_literal(default, &java_type)).as_str());
+            }
+        }
+
+        content.push_str("\t}\n\n");
     }
 
-    fn write_getters(&self, content: &mut String, packet: &Packet) {
+    fn write_getters(&self, content: &mut String, packet: &Packet, scope: &JavaScope) {
 
         for field in &packet.fields {
 
-            let java_type = convert_to_java_type(&field.typ);
-            let camel_case = to_camel_case(field.name.as_str());
+            let java_type = convert_to_java_type(&field.typ, scope);
+            let camel_case = java_field_name(field);
             let capitalized = capitalize(camel_case.as_str());
 
             let getter_name = match java_type {
@@ -335,8 +960,17 @@ impl JavaGenerator {
                 _ => format!("get{}", capitalized)
             };
 
-            content.push_str(format!("\tpublic {} {}() {{\n", java_type, getter_name).as_str());
-            content.push_str(format!("\t\treturn {};\n", camel_case).as_str());
+            self.write_javadoc(content, &self.field_doc(field, scope), "\t");
+            self.write_deprecated(content, &field.attributes, "\t");
+
+            if self.options.optional_getters && matches!(java_type, JavaType::Optional(_)) {
+                content.push_str(format!("\tpublic Optional<{}> {}() {{\n", java_type, getter_name).as_str());
+                content.push_str(format!("\t\treturn Optional.ofNullable({});\n", camel_case).as_str());
+            } else {
+                content.push_str(format!("\tpublic {} {}() {{\n", java_type, getter_name).as_str());
+                content.push_str(format!("\t\treturn {};\n", camel_case).as_str());
+            }
+
             content.push_str("\t}\n\n");
 
 
@@ -344,15 +978,20 @@ impl JavaGenerator {
 
     }
 
-    fn write_setters(&self, content: &mut String, packet: &Packet) {
+    fn write_setters(&self, content: &mut String, packet: &Packet, scope: &JavaScope) {
 
         for field in &packet.fields {
 
-            let java_type = convert_to_java_type(&field.typ);
-            let camel_case = to_camel_case(field.name.as_str());
+            let java_type = convert_to_java_type(&field.typ, scope);
+            let camel_case = java_field_name(field);
             let capitalized = capitalize(camel_case.as_str());
 
+            self.write_javadoc(content, &self.field_doc(field, scope), "\t");
+            self.write_deprecated(content, &field.attributes, "\t");
             content.push_str(format!("\tpublic void set{}({} value) {{\n", capitalized, java_type).as_str());
+            if !field.constraints.is_empty() {
+                content.push_str(format!("\t\tvalidate{}(value);\n", capitalized).as_str());
+            }
             content.push_str(format!("\t\tthis.{} = value;\n", camel_case).as_str());
             content.push_str("\t}\n\n");
 
@@ -361,20 +1000,86 @@ impl JavaGenerator {
 
     }
 
-    fn write_encode_method(&self, content: &mut String, packet: &Packet) {
-        content.push_str("\t@Override\n");
-        content.push_str("\tpublic void encode(ByteArrayOutputStream out, boolean encodeId) {\n\n");
+    fn write_validators(&self, content: &mut String, packet: &Packet, scope: &JavaScope) {
 
+        for field in packet.fields.iter().filter(|field| !field.constraints.is_empty()) {
 
-        content.push_str("\t\tif (encodeId) BinaryWriter.writeByte(out, PACKET_ID);\n\n");
+            let java_type = convert_to_java_type(&field.typ, scope);
+            let capitalized = capitalize(java_field_name(field).as_str());
+            let label = format!("Field '{}' in {}", field.name, packet_class_name(packet));
+
+            content.push_str(format!(
+                "\t{} static void validate{}({} value) {{\n", self.member_modifier(), capitalized, java_type
+            ).as_str());
+            self.write_constraint_checks(content, &label, field, "value", "\t\t");
+            content.push_str("\t}\n\n");
 
-        for field in &packet.fields {
-            let name = field.name.as_str();
-            let java_type = convert_to_java_type(&field.typ);
-            self.write_encode_field(content, name, &java_type, 1);
         }
 
-        content.push_str("\t}\n");
+    }
+
+    fn write_constraint_checks(&self, content: &mut String, label: &str, field: &Field, name: &str, indent: &str) {
+
+        let (field_type, nullable) = match &field.typ {
+            FieldType::Optional(inner_type) => (inner_type.as_ref(), true),
+            field_type => (field_type, !field_type.is_primitive()),
+        };
+
+        for constraint in &field.constraints {
+            let (condition, message) = constraint_check(field_type, constraint, name);
+            let condition = if nullable { format!("{} != null && {}", name, condition) } else { condition };
+            content.push_str(format!(
+                "{}if ({}) throw new IllegalArgumentException(\"{} {});\n", indent, condition, label, message
+            ).as_str());
+        }
+
+    }
+
+    fn write_encode_method(&self, content: &mut String, packet: &Packet, scope: &JavaScope) {
+        match (packet.kind, packet.version) {
+            (PacketKind::Packet, None) => {
+                content.push_str("\t@Override\n");
+                content.push_str("\tpublic void encode(ByteArrayOutputStream out, boolean encodeId) {\n\n");
+
+
+                content.push_str("\t\tif (encodeId) BinaryWriter.writeByte(out, PACKET_ID);\n\n");
+            }
+            (PacketKind::Packet, Some(_)) => {
+                content.push_str("\t@Override\n");
+                content.push_str("\tpublic void encode(ByteArrayOutputStream out, boolean encodeId) {\n");
+                content.push_str("\t\tencode(out, encodeId, VERSION);\n");
+                content.push_str("\t}\n\n");
+
+                content.push_str("\tpublic void encode(ByteArrayOutputStream out, boolean encodeId, int protocolVersion) {\n\n");
+                content.push_str("\t\tif (encodeId) BinaryWriter.writeByte(out, PACKET_ID);\n\n");
+            }
+            (PacketKind::Struct, None) => {
+                content.push_str("\tpublic void encode(ByteArrayOutputStream out) {\n\n");
+            }
+            (PacketKind::Struct, Some(_)) => {
+                content.push_str("\tpublic void encode(ByteArrayOutputStream out) {\n");
+                content.push_str("\t\tencode(out, VERSION);\n");
+                content.push_str("\t}\n\n");
+
+                content.push_str("\tpublic void encode(ByteArrayOutputStream out, int protocolVersion) {\n\n");
+            }
+        }
+
+        for field in &packet.fields {
+            let name = java_field_name(field);
+            let java_type = convert_to_java_type(&field.typ, scope);
+
+            match packet.version.and(version_condition(&field.attributes)) {
+                Some(condition) => {
+                    content.push_str(format!("\t\tif ({}) {{\n\n", condition).as_str());
+                    self.write_encode_field(content, name.as_str(), &java_type, scope, 2);
+                    content.push_str("\t\t}\n\n");
+                }
+                None => self.write_encode_field(content, name.as_str(), &java_type, scope, 1),
+            }
+        }
+
+        content.push_str("\t}\n");
     }
 
     fn write_encode_field(
@@ -382,11 +1087,12 @@ impl JavaGenerator {
         content: &mut String,
         name: &str,
         java_type: &JavaType,
+        scope: &JavaScope,
         indent: usize
     ) {
 
         let indent_str = "\t".repeat(indent);
-        let camel_case = to_camel_case(name);
+        let camel_case = name;
 
         match java_type {
             JavaType::Bool => {
@@ -434,11 +1140,26 @@ impl JavaGenerator {
                     "\t{}BinaryWriter.writeString(out, {});\n\n", indent_str, camel_case
                 ).as_str());
             }
-            JavaType::Array(inner) => {
-
+            JavaType::Bytes => {
                 content.push_str(format!(
-                    "\t{}BinaryWriter.writeInt(out, {}.size());\n", indent_str, camel_case
+                    "\t{}BinaryWriter.writeInt(out, {}.length);\n", indent_str, camel_case
+                ).as_str());
+                content.push_str(format!(
+                    "\t{}BinaryWriter.writeBytes(out, {});\n\n", indent_str, camel_case
                 ).as_str());
+            }
+            JavaType::Array(inner) | JavaType::FixedArray(inner, _) | JavaType::Set(inner) => {
+
+                if let JavaType::FixedArray(_, size) = java_type {
+                    content.push_str(format!(
+                        "\t{}if ({}.size() != {}) throw new IllegalArgumentException(\"Array '{}' must contain exactly {} elements, actual size is \" + {}.size());\n",
+                        indent_str, camel_case, size, camel_case, size, camel_case
+                    ).as_str());
+                } else {
+                    content.push_str(format!(
+                        "\t{}BinaryWriter.writeInt(out, {}.size());\n", indent_str, camel_case
+                    ).as_str());
+                }
 
                 let item_name = match indent > 1 {
                     true => format!("{}Item{}", camel_case, indent - 1),
@@ -446,30 +1167,126 @@ impl JavaGenerator {
                 };
 
                 content.push_str(format!("\t{}for ({} {} : {}) {{\n\n", indent_str, inner, item_name, camel_case).as_str());
-                self.write_encode_field(content, item_name.as_str(), &**inner, indent + 1);
+                self.write_encode_field(content, item_name.as_str(), inner, scope, indent + 1);
+                content.push_str(format!("\t{}}}\n\n", indent_str).as_str());
+            }
+            JavaType::Optional(inner) => {
+
+                content.push_str(format!(
+                    "\t{}BinaryWriter.writeBoolean(out, {} != null);\n", indent_str, camel_case
+                ).as_str());
+
+                content.push_str(format!("\t{}if ({} != null) {{\n\n", indent_str, camel_case).as_str());
+                self.write_encode_field(content, name, inner, scope, indent + 1);
+                content.push_str(format!("\t{}}}\n\n", indent_str).as_str());
+            }
+            JavaType::Map(key_type, value_type) => {
+
+                content.push_str(format!(
+                    "\t{}BinaryWriter.writeInt(out, {}.size());\n", indent_str, camel_case
+                ).as_str());
+
+                let entry_name = format!("{}Entry", camel_case);
+                let key_name = format!("{}Key", camel_case);
+                let value_name = format!("{}Value", camel_case);
+                let key_type_name = primitive_or_wrapper_string(key_type);
+                let value_type_name = primitive_or_wrapper_string(value_type);
+
+                content.push_str(format!(
+                    "\t{}for (Map.Entry<{}, {}> {} : {}.entrySet()) {{\n",
+                    indent_str, key_type_name, value_type_name, entry_name, camel_case
+                ).as_str());
+                content.push_str(format!(
+                    "\t\t{}{} {} = {}.getKey();\n", indent_str, key_type_name, key_name, entry_name
+                ).as_str());
+                content.push_str(format!(
+                    "\t\t{}{} {} = {}.getValue();\n\n", indent_str, value_type_name, value_name, entry_name
+                ).as_str());
+                self.write_encode_field(content, key_name.as_str(), key_type, scope, indent + 1);
+                self.write_encode_field(content, value_name.as_str(), value_type, scope, indent + 1);
                 content.push_str(format!("\t{}}}\n\n", indent_str).as_str());
             }
-            JavaType::Class(_) => {
+            JavaType::Class(class_name) => {
+                content.push_str(format!(
+                    "\t{}{}.encode(out, false{});\n\n", indent_str, camel_case, scope.version_arg(class_name)
+                ).as_str());
+            }
+            JavaType::Struct(class_name) | JavaType::Enum(class_name) | JavaType::Union(class_name) => {
                 content.push_str(format!(
-                    "\t{}{}.encode(out, false);\n\n", indent_str, camel_case
+                    "\t{}{}.encode(out{});\n\n", indent_str, camel_case, scope.version_arg(class_name)
                 ).as_str());
             }
         }
     }
 
-    fn write_decode_method(&self, content: &mut String, packet: &Packet) {
-        content.push_str(format!(
-            "\tpublic static {} decode(InputStream in) throws IOException {{\n",
-            packet.name
-        ).as_str());
+    fn write_decode_method(&self, content: &mut String, packet: &Packet, scope: &JavaScope) {
+        if packet.version.is_some() {
+            content.push_str(format!(
+                "\tpublic static {} decode(InputStream in) throws IOException {{\n",
+                packet_class_name(packet)
+            ).as_str());
+            content.push_str("\t\treturn decode(in, VERSION);\n");
+            content.push_str("\t}\n\n");
+
+            content.push_str(format!(
+                "\tpublic static {} decode(InputStream in, int protocolVersion) throws IOException {{\n",
+                packet_class_name(packet)
+            ).as_str());
+        } else {
+            content.push_str(format!(
+                "\tpublic static {} decode(InputStream in) throws IOException {{\n",
+                packet_class_name(packet)
+            ).as_str());
+        }
 
         content.push_str("\t\ttry {\n");
         for field in &packet.fields {
-            let java_type = convert_to_java_type(&field.typ);
-            self.write_decode_field(content, field.name.as_str(), &java_type, 1);
+            let java_type = convert_to_java_type(&field.typ, scope);
+
+            if let Some(condition) = packet.version.and(version_condition(&field.attributes)) {
+
+                let value_name = format!("{}_value", field.name);
+
+                content.push_str(format!(
+                    "\t\t\t{} {} = {};\n", java_type, field.name, missing_value(field, &java_type)
+                ).as_str());
+                content.push_str(format!("\t\t\tif ({}) {{\n", condition).as_str());
+                self.write_decode_field(content, value_name.as_str(), &java_type, scope, 2);
+                content.push_str(format!("\t\t\t\t{} = {};\n", field.name, value_name).as_str());
+
+                if !field.constraints.is_empty() {
+                    content.push_str(format!(
+                        "\t\t\t\tvalidate{}({});\n", capitalize(java_field_name(field).as_str()), field.name
+                    ).as_str());
+                }
+
+                content.push_str("\t\t\t}\n\n");
+
+                if let (JavaType::Optional(_), Some(default)) = (&java_type, &field.default) {
+                    content.push_str(format!(
+                        "\t\t\tif ({} == null) {} = {};\n\n", field.name, field.name, java_literal(default, &java_type)
+                    ).as_str());
+                }
+
+                continue;
+            }
+
+            self.write_decode_field(content, field.name.as_str(), &java_type, scope, 1);
+
+            if let (JavaType::Optional(_), Some(default)) = (&java_type, &field.default) {
+                content.push_str(format!(
+                    "\t\t\tif ({} == null) {} = {};\n\n", field.name, field.name, java_literal(default, &java_type)
+                ).as_str());
+            }
+
+            if !field.constraints.is_empty() {
+                content.push_str(format!(
+                    "\t\t\tvalidate{}({});\n\n", capitalize(java_field_name(field).as_str()), field.name
+                ).as_str());
+            }
         }
 
-        content.push_str(format!("\t\t\treturn new {}(\n", packet.name).as_str());
+        content.push_str(format!("\t\t\treturn new {}(\n", packet_class_name(packet)).as_str());
         for (i, field) in packet.fields.iter().enumerate() {
             let comma = if i == packet.fields.len() - 1 { "" } else { "," };
             content.push_str(format!("\t\t\t\t{}{}\n", field.name, comma).as_str());
@@ -477,7 +1294,7 @@ impl JavaGenerator {
         content.push_str("\t\t\t);\n\n");
 
         content.push_str("\t\t} catch (Exception e) {\n");
-        content.push_str(format!("\t\t\tthrow new IOException(\"Failed to decode {}\", e);\n\n", packet.name).as_str());
+        content.push_str(format!("\t\t\tthrow new IOException(\"Failed to decode {}\", e);\n\n", packet_class_name(packet)).as_str());
         content.push_str("\t\t}\n");
 
         content.push_str("\t}\n\n");
@@ -488,6 +1305,7 @@ impl JavaGenerator {
         content: &mut String,
         name: &str,
         java_type: &JavaType,
+        scope: &JavaScope,
         indent: usize
     ) {
 
@@ -523,12 +1341,23 @@ impl JavaGenerator {
                 content.push_str(format!("\t\t{}String {} = BinaryReader.readString(in);\n\n", indent_str, name).as_str());
 
             }
-            JavaType::Array(inner_type) => {
-
+            JavaType::Bytes => {
                 let length_name = format!("{}Length", camel_case);
+                content.push_str(format!("\t\t{}int {} = BinaryReader.readInt(in);\n", indent_str, length_name).as_str());
+                content.push_str(format!("\t\t{}byte[] {} = BinaryReader.readBytes(in, {});\n\n", indent_str, name, length_name).as_str());
+            }
+            JavaType::Array(inner_type) | JavaType::FixedArray(inner_type, _) => {
+
                 let index_name = format!("{}Index", camel_case);
 
-                content.push_str(format!("\t\t{}int {} = BinaryReader.readInt(in);\n", indent_str, length_name).as_str());
+                let length = match java_type {
+                    JavaType::FixedArray(_, size) => size.to_string(),
+                    _ => {
+                        let length_name = format!("{}Length", camel_case);
+                        content.push_str(format!("\t\t{}int {} = BinaryReader.readInt(in);\n", indent_str, length_name).as_str());
+                        length_name
+                    }
+                };
 
                 content.push_str(
                     format!("\t\t{}ArrayList<{}> {} = new ArrayList<>();\n",
@@ -538,7 +1367,7 @@ impl JavaGenerator {
 
                 content.push_str(
                     format!("\t\t{}for (int {} = 0; {} < {}; {}++) {{\n",
-                                         indent_str, index_name, index_name, length_name, index_name).as_str()
+                                         indent_str, index_name, index_name, length, index_name).as_str()
                 );
 
                 match &**inner_type {
@@ -551,16 +1380,22 @@ impl JavaGenerator {
                     JavaType::Double |
                     JavaType::Char |
                     JavaType::Str |
-                    JavaType::Class(_) => {
+                    JavaType::Bytes |
+                    JavaType::Optional(_) |
+                    JavaType::Map(_, _) |
+                    JavaType::Class(_) |
+                    JavaType::Struct(_) |
+                    JavaType::Enum(_) |
+                    JavaType::Union(_) => {
                         let item_name = format!("{}_item", name);
-                        self.write_decode_field(content, item_name.as_str(), &**inner_type, indent + 1);
+                        self.write_decode_field(content, item_name.as_str(), inner_type, scope, indent + 1);
                         content.push_str(
                             format!("\t\t\t{}{}.add({});\n", indent_str, name, item_name).as_str(),
                         );
                     }
-                    JavaType::Array(_) => {
+                    JavaType::Array(_) | JavaType::FixedArray(_, _) | JavaType::Set(_) => {
                         let item_name = format!("{}_item", name);
-                        self.write_decode_field(content, item_name.as_str(), &**inner_type, indent + 1);
+                        self.write_decode_field(content, item_name.as_str(), inner_type, scope, indent + 1);
                         content.push_str(
                             format!("\t\t\t{}{}.add({});\n", indent_str, name, item_name).as_str(),
                         );
@@ -570,30 +1405,111 @@ impl JavaGenerator {
                 content.push_str(format!("\t\t{}}}\n\n", indent_str).as_str());
 
             }
-            JavaType::Class(class_name) => {
-                content.push_str(format!("\t\t{}{} {} = {}.decode(in);\n\n", indent_str, class_name, name, class_name).as_str());
+            JavaType::Set(inner_type) => {
+
+                let length_name = format!("{}Length", camel_case);
+                let index_name = format!("{}Index", camel_case);
+                let item_name = format!("{}_item", name);
+
+                content.push_str(format!("\t\t{}int {} = BinaryReader.readInt(in);\n", indent_str, length_name).as_str());
+
+                content.push_str(
+                    format!("\t\t{}LinkedHashSet<{}> {} = new LinkedHashSet<>();\n",
+                            indent_str, primitive_or_wrapper_string(inner_type), name
+                    ).as_str()
+                );
+
+                content.push_str(
+                    format!("\t\t{}for (int {} = 0; {} < {}; {}++) {{\n",
+                            indent_str, index_name, index_name, length_name, index_name).as_str()
+                );
+
+                self.write_decode_field(content, item_name.as_str(), inner_type, scope, indent + 1);
+
+                if self.options.drop_set_duplicates {
+                    content.push_str(format!("\t\t\t{}{}.add({});\n", indent_str, name, item_name).as_str());
+                } else {
+                    content.push_str(format!(
+                        "\t\t\t{}if (!{}.add({})) throw new IllegalArgumentException(\"Duplicate set element \" + {});\n",
+                        indent_str, name, item_name, item_name
+                    ).as_str());
+                }
+
+                content.push_str(format!("\t\t{}}}\n\n", indent_str).as_str());
+
+            }
+            JavaType::Optional(inner_type) => {
+
+                let value_name = format!("{}_value", name);
+
+                content.push_str(format!("\t\t{}{} {} = null;\n", indent_str, java_type, name).as_str());
+                content.push_str(format!("\t\t{}if (BinaryReader.readBoolean(in)) {{\n", indent_str).as_str());
+                self.write_decode_field(content, value_name.as_str(), inner_type, scope, indent + 1);
+                content.push_str(format!("\t\t\t{}{} = {};\n", indent_str, name, value_name).as_str());
+                content.push_str(format!("\t\t{}}}\n\n", indent_str).as_str());
+
+            }
+            JavaType::Map(key_type, value_type) => {
+
+                let length_name = format!("{}Length", camel_case);
+                let index_name = format!("{}Index", camel_case);
+                let key_name = format!("{}_key", name);
+                let value_name = format!("{}_value", name);
+
+                content.push_str(format!("\t\t{}int {} = BinaryReader.readInt(in);\n", indent_str, length_name).as_str());
+
+                content.push_str(
+                    format!("\t\t{}LinkedHashMap<{}, {}> {} = new LinkedHashMap<>();\n",
+                            indent_str, primitive_or_wrapper_string(key_type), primitive_or_wrapper_string(value_type), name
+                    ).as_str()
+                );
+
+                content.push_str(
+                    format!("\t\t{}for (int {} = 0; {} < {}; {}++) {{\n",
+                            indent_str, index_name, index_name, length_name, index_name).as_str()
+                );
+
+                self.write_decode_field(content, key_name.as_str(), key_type, scope, indent + 1);
+                self.write_decode_field(content, value_name.as_str(), value_type, scope, indent + 1);
+                content.push_str(
+                    format!("\t\t\t{}{}.put({}, {});\n", indent_str, name, key_name, value_name).as_str(),
+                );
+
+                content.push_str(format!("\t\t{}}}\n\n", indent_str).as_str());
+
+            }
+            JavaType::Class(class_name) | JavaType::Struct(class_name) | JavaType::Enum(class_name) | JavaType::Union(class_name) => {
+                content.push_str(format!(
+                    "\t\t{}{} {} = {}.decode(in{});\n\n", indent_str, class_name, name, class_name, scope.version_arg(class_name)
+                ).as_str());
             }
         }
     }
 
     fn write_registry_registration(&self, content: &mut String, packet: &Packet) {
         content.push_str("\tstatic {\n");
-        content.push_str(format!("\t\tPacketRegistries.DEFAULT.register(PACKET_ID, {}::decode);\n", packet.name).as_str());
+        let registry = match packet.direction {
+            Some(direction) => direction.to_string().to_uppercase(),
+            None => "DEFAULT".to_string(),
+        };
+        content.push_str(format!("\t\tPacketRegistries.{}.register(PACKET_ID, {}::decode);\n", registry, packet_class_name(packet)).as_str());
         content.push_str("\t}\n\n")
     }
 
     fn write_to_string(&self, content: &mut String, packet: &Packet) {
         content.push_str("\t@Override\n");
         content.push_str("\tpublic String toString() {\n");
-        content.push_str(format!("\t\treturn \"{}{{\" +\n", packet.name).as_str());
+        content.push_str(format!("\t\treturn \"{}{{\" +\n", packet_class_name(packet)).as_str());
 
         if packet.fields.is_empty() {
             content.push_str("\t\t'}';\n");
         } else {
             for (i, field) in packet.fields.iter().enumerate() {
-                let camel_case = to_camel_case(&field.name);
+                let camel_case = java_field_name(field);
                 let prefix = if i == 0 { "" } else { "\", \" + " };
-                content.push_str(format!("\t\t\t{}\"{}=\" + {} +\n", prefix, camel_case, camel_case).as_str());
+                content.push_str(format!(
+                    "\t\t\t{}\"{}=\" + {} +\n", prefix, camel_case, to_string_expression(&field.typ, &camel_case)
+                ).as_str());
             }
             content.push_str("\t\t'}';\n");
         }
@@ -606,12 +1522,17 @@ impl JavaGenerator {
         content.push_str("\tpublic boolean equals(Object o) {\n");
         content.push_str("\t\tif (this == o) return true;\n");
         content.push_str("\t\tif (o == null || getClass() != o.getClass()) return false;\n");
-        content.push_str(format!("\t\t{} that = ({}) o;\n", packet.name, packet.name).as_str());
+        let class_name = packet_class_name(packet);
+        content.push_str(format!("\t\t{} that = ({}) o;\n", class_name, class_name).as_str());
 
         let comparisons: Vec<String> = packet.fields.iter().map(|field| {
-            let camel_case = to_camel_case(&field.name);
+            let camel_case = java_field_name(field);
             match &field.typ {
-                FieldType::Array(_) | FieldType::Str | FieldType::Nested(_) => {
+                field_type if is_bytes(field_type) => {
+                    format!("Arrays.equals({}, that.{})", camel_case, camel_case)
+                }
+                FieldType::Array(_) | FieldType::FixedArray(_, _) | FieldType::Set(_) | FieldType::Optional(_) | FieldType::Map(_, _) |
+                FieldType::Str | FieldType::Nested(_) | FieldType::Union(_) => {
                     format!("Objects.equals({}, that.{})", camel_case, camel_case)
                 }
                 _ => format!("{} == that.{}", camel_case, camel_case),
@@ -636,7 +1557,7 @@ impl JavaGenerator {
             content.push_str("\t\treturn 0;\n");
         } else {
             let args: Vec<String> = packet.fields.iter()
-                .map(|field| to_camel_case(&field.name))
+                .map(|field| hash_expression(&field.typ, &java_field_name(field)))
                 .collect();
             content.push_str(&format!("\t\treturn Objects.hash({});\n", args.join(", ")));
         }
@@ -646,39 +1567,996 @@ impl JavaGenerator {
 
 }
 
-impl Generator for JavaGenerator {
+impl JavaGenerator {
 
-    fn generate(&self, packets: &Vec<Packet>) -> MorphResult<()> {
+    fn generate_enum(&self, enum_decl: &Enum, schema: &Schema) -> String {
 
-        let output_dir = Path::new(&self.options.output_dir);
+        let mut content = String::new();
+        let scope = &JavaScope { schema, type_names: HashMap::new(), versioned_types: Vec::new() };
+
+        self.write_package(&mut content, &enum_decl.namespace);
+        self.write_enum_imports(&mut content);
+        content.push('\n');
+        self.write_javadoc(&mut content, &enum_decl.doc, "");
+        content.push_str(format!("public enum {} {{\n\n", enum_decl.name).as_str());
+        self.write_enum_variants(&mut content, enum_decl, scope);
+        self.write_enum_value(&mut content, enum_decl, scope);
+        self.write_enum_from_value(&mut content, enum_decl, scope);
+        self.write_enum_encode_method(&mut content, enum_decl, scope);
+        self.write_enum_decode_method(&mut content, enum_decl, scope);
+        content.push('}');
 
-        if let Err(err) = std::fs::create_dir_all(output_dir) {
-            let morph_err = GenerationError {
-                message: format!("Failed to create output directory: {}", err.to_string()),
+        content
+
+    }
+
+    fn write_enum_imports(&self, content: &mut String) {
+        content.push_str("import me.bottdev.morph.runtime.BinaryWriter;\n");
+        content.push_str("import me.bottdev.morph.runtime.BinaryReader;\n\n");
+        content.push_str("import java.io.ByteArrayOutputStream;\n");
+        content.push_str("import java.io.InputStream;\n");
+        content.push_str("import java.io.IOException;\n");
+    }
+
+    fn write_enum_variants(&self, content: &mut String, enum_decl: &Enum, scope: &JavaScope) {
+
+        let java_type = convert_to_java_type(&enum_decl.typ, scope);
+
+        for (i, variant) in enum_decl.variants.iter().enumerate() {
+            let separator = if i == enum_decl.variants.len() - 1 { ";" } else { "," };
+            let value = match java_type {
+                JavaType::Byte => format!("(byte) {}", variant.value),
+                JavaType::Short => format!("(short) {}", variant.value),
+                JavaType::Long => format!("{}L", variant.value),
+                _ => variant.value.to_string(),
             };
-            return Errors(vec![Box::new(morph_err)]);
+            self.write_javadoc(content, &variant.doc, "\t");
+            content.push_str(format!("\t{}({}){}\n", variant.name, value, separator).as_str());
         }
 
-        for packet in packets {
+        content.push('\n');
+    }
 
-            let content = self.generate_class(packet);
-            let path = output_dir.join(format!("{}.java", packet.name));
+    fn write_enum_value(&self, content: &mut String, enum_decl: &Enum, scope: &JavaScope) {
 
-            match std::fs::write(&path, content) {
-                Ok(_) => {}
-                Err(err) => {
-                    let morph_err = GenerationError {
-                        message: format!("Failed to write {}.java file: {}", packet.name, err),
-                    };
-                    return Errors(vec![Box::new(morph_err)]);
-                }
-            }
+        let java_type = convert_to_java_type(&enum_decl.typ, scope);
+
+        content.push_str(format!("\tprivate final {} value;\n\n", java_type).as_str());
+
+        content.push_str(format!("\t{}({} value) {{\n", enum_decl.name, java_type).as_str());
+        content.push_str("\t\tthis.value = value;\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str(format!("\tpublic {} getValue() {{\n", java_type).as_str());
+        content.push_str("\t\treturn value;\n");
+        content.push_str("\t}\n\n");
+    }
+
+    fn write_enum_from_value(&self, content: &mut String, enum_decl: &Enum, scope: &JavaScope) {
+
+        let java_type = convert_to_java_type(&enum_decl.typ, scope);
+
+        content.push_str(format!("\tpublic static {} fromValue({} value) {{\n", enum_decl.name, java_type).as_str());
+        content.push_str(format!("\t\tfor ({} variant : values()) {{\n", enum_decl.name).as_str());
+        content.push_str("\t\t\tif (variant.value == value) return variant;\n");
+        content.push_str("\t\t}\n");
+        content.push_str(format!(
+            "\t\tthrow new IllegalArgumentException(\"Unknown value \" + value + \" for enum {}\");\n",
+            enum_decl.name
+        ).as_str());
+        content.push_str("\t}\n\n");
+    }
+
+    fn write_enum_encode_method(&self, content: &mut String, enum_decl: &Enum, scope: &JavaScope) {
+
+        let java_type = convert_to_java_type(&enum_decl.typ, scope);
+
+        content.push_str("\tpublic void encode(ByteArrayOutputStream out) {\n\n");
+        self.write_encode_field(content, "value", &java_type, scope, 1);
+        content.push_str("\t}\n\n");
+    }
 
+    fn write_enum_decode_method(&self, content: &mut String, enum_decl: &Enum, scope: &JavaScope) {
 
+        let java_type = convert_to_java_type(&enum_decl.typ, scope);
+
+        content.push_str(format!(
+            "\tpublic static {} decode(InputStream in) throws IOException {{\n",
+            enum_decl.name
+        ).as_str());
+        self.write_decode_field(content, "value", &java_type, scope, 0);
+        content.push_str("\t\treturn fromValue(value);\n");
+        content.push_str("\t}\n\n");
+    }
+
+}
+
+fn flags_literal(java_type: &JavaType, mask: u64) -> String {
+    match java_type {
+        JavaType::Byte => format!("(byte) 0x{:X}", mask),
+        JavaType::Short => format!("(short) 0x{:X}", mask),
+        JavaType::Long => format!("0x{:X}L", mask),
+        _ => format!("0x{:X}", mask),
+    }
+}
+
+impl JavaGenerator {
+
+    fn generate_flags(&self, flags: &Enum, schema: &Schema) -> String {
+
+        let mut content = String::new();
+        let scope = &JavaScope { schema, type_names: HashMap::new(), versioned_types: Vec::new() };
+
+        self.write_package(&mut content, &flags.namespace);
+        self.write_enum_imports(&mut content);
+        content.push_str("import java.util.ArrayList;\n");
+        content.push_str("import java.util.List;\n");
+        content.push('\n');
+        self.write_javadoc(&mut content, &flags.doc, "");
+        content.push_str(format!("public final class {} {{\n\n", flags.name).as_str());
+        self.write_flags_constants(&mut content, flags, scope);
+        self.write_flags_value(&mut content, flags, scope);
+        self.write_flags_operations(&mut content, flags, scope);
+        self.write_enum_encode_method(&mut content, flags, scope);
+        self.write_enum_decode_method(&mut content, flags, scope);
+        self.write_flags_object_methods(&mut content, flags);
+        content.push('}');
+
+        content
+
+    }
+
+    fn write_flags_constants(&self, content: &mut String, flags: &Enum, scope: &JavaScope) {
+
+        let java_type = convert_to_java_type(&flags.typ, scope);
+
+        for variant in &flags.variants {
+            self.write_javadoc(content, &variant.doc, "\t");
+            content.push_str(format!(
+                "\tpublic static final {} {} = new {}({});\n",
+                flags.name, variant.name, flags.name, flags_literal(&java_type, 1u64 << variant.value)
+            ).as_str());
         }
 
-        Success(())
+        content.push('\n');
+    }
+
+    fn write_flags_value(&self, content: &mut String, flags: &Enum, scope: &JavaScope) {
+
+        let java_type = convert_to_java_type(&flags.typ, scope);
+        let known_bits = flags.variants.iter().fold(0u64, |mask, variant| mask | 1u64 << variant.value);
+
+        content.push_str(format!("\tprivate final {} value;\n\n", java_type).as_str());
+
+        content.push_str(format!("\tprivate {}({} value) {{\n", flags.name, java_type).as_str());
+        content.push_str("\t\tthis.value = value;\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str(format!("\tpublic {} getValue() {{\n", java_type).as_str());
+        content.push_str("\t\treturn value;\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str(format!("\tpublic static {} fromValue({} value) {{\n", flags.name, java_type).as_str());
+        content.push_str(format!("\t\tif ((value & ~{}) != 0) {{\n", flags_literal(&java_type, known_bits)).as_str());
+        content.push_str(format!(
+            "\t\t\tthrow new IllegalArgumentException(\"Unknown bits in value \" + value + \" for flags {}\");\n",
+            flags.name
+        ).as_str());
+        content.push_str("\t\t}\n");
+        content.push_str(format!("\t\treturn new {}(value);\n", flags.name).as_str());
+        content.push_str("\t}\n\n");
+    }
+
+    fn write_flags_operations(&self, content: &mut String, flags: &Enum, scope: &JavaScope) {
+
+        let java_type = convert_to_java_type(&flags.typ, scope);
+        let name = &flags.name;
+
+        content.push_str(format!("\tpublic static {} of({}... flags) {{\n", name, name).as_str());
+        content.push_str(format!("\t\t{} value = 0;\n", java_type).as_str());
+        content.push_str(format!("\t\tfor ({} flag : flags) {{\n", name).as_str());
+        content.push_str(format!("\t\t\tvalue = ({}) (value | flag.value);\n", java_type).as_str());
+        content.push_str("\t\t}\n");
+        content.push_str(format!("\t\treturn new {}(value);\n", name).as_str());
+        content.push_str("\t}\n\n");
+
+        content.push_str(format!("\tpublic boolean has({} flags) {{\n", name).as_str());
+        content.push_str("\t\treturn (value & flags.value) == flags.value;\n");
+        content.push_str("\t}\n\n");
 
+        content.push_str(format!("\tpublic {} with({} flags) {{\n", name, name).as_str());
+        content.push_str(format!("\t\treturn new {}(({}) (value | flags.value));\n", name, java_type).as_str());
+        content.push_str("\t}\n\n");
+
+        content.push_str(format!("\tpublic {} without({} flags) {{\n", name, name).as_str());
+        content.push_str(format!("\t\treturn new {}(({}) (value & ~flags.value));\n", name, java_type).as_str());
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tpublic boolean isEmpty() {\n");
+        content.push_str("\t\treturn value == 0;\n");
+        content.push_str("\t}\n\n");
     }
 
-}
\ No newline at end of file
+    fn write_flags_object_methods(&self, content: &mut String, flags: &Enum) {
+
+        content.push_str("\t@Override\n");
+        content.push_str("\tpublic boolean equals(Object o) {\n");
+        content.push_str("\t\tif (this == o) return true;\n");
+        content.push_str("\t\tif (o == null || getClass() != o.getClass()) return false;\n");
+        content.push_str(format!("\t\treturn value == (({}) o).value;\n", flags.name).as_str());
+        content.push_str("\t}\n\n");
+
+        content.push_str("\t@Override\n");
+        content.push_str("\tpublic int hashCode() {\n");
+        content.push_str("\t\treturn Long.hashCode(value);\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\t@Override\n");
+        content.push_str("\tpublic String toString() {\n");
+        content.push_str("\t\tList<String> names = new ArrayList<>();\n");
+        for variant in &flags.variants {
+            content.push_str(format!("\t\tif (has({})) names.add(\"{}\");\n", variant.name, variant.name).as_str());
+        }
+        content.push_str(format!("\t\treturn \"{}\" + names;\n", flags.name).as_str());
+        content.push_str("\t}\n\n");
+    }
+
+}
+
+impl JavaGenerator {
+
+    fn generate_union(&self, union: &Union, schema: &Schema) -> String {
+
+        let mut content = String::new();
+        let variant_names: Vec<String> = union.variants.iter()
+            .map(|variant| self.union_variant_class_name(variant))
+            .collect();
+        let class_names: Vec<String> = std::iter::once(union.name.clone())
+            .chain(variant_names.iter().cloned())
+            .collect();
+        let scope = self.create_scope(&union.qualified_name(), &class_names, &union.variants, schema);
+        let scope = &scope;
+
+        self.write_package(&mut content, &union.namespace);
+        self.write_union_imports(&mut content);
+        if self.has_arrays(&union.variants) {
+            self.write_import_list(&mut content);
+        }
+        if self.has_maps(&union.variants) {
+            self.write_import_map(&mut content);
+        }
+        if self.has_sets(&union.variants) {
+            self.write_import_set(&mut content);
+        }
+        if self.has_bytes(&union.variants) {
+            self.write_import_arrays(&mut content);
+        }
+        self.write_type_imports(&mut content, &union.namespace, &union.variants, scope);
+        content.push('\n');
+        self.write_javadoc(&mut content, &union.doc, "");
+
+        let permits: Vec<String> = variant_names.iter()
+            .map(|variant_name| format!("{}.{}", union.name, variant_name))
+            .collect();
+        content.push_str(format!(
+            "public sealed interface {} permits {} {{\n\n", union.name, permits.join(", ")
+        ).as_str());
+        if let Some(version) = union.version {
+            content.push_str(format!("\tint VERSION = {};\n\n", version).as_str());
+        }
+        content.push_str("\tbyte getTag();\n\n");
+        if union.version.is_some() {
+            content.push_str("\tdefault void encode(ByteArrayOutputStream out) {\n");
+            content.push_str("\t\tencode(out, VERSION);\n");
+            content.push_str("\t}\n\n");
+            content.push_str("\tvoid encode(ByteArrayOutputStream out, int protocolVersion);\n\n");
+        } else {
+            content.push_str("\tvoid encode(ByteArrayOutputStream out);\n\n");
+        }
+        self.write_union_decode_method(&mut content, union, &variant_names);
+
+        for (tag, variant) in union.variants.iter().enumerate() {
+            self.write_union_variant(&mut content, union, variant, tag, scope);
+        }
+
+        content.push('}');
+
+        content
+
+    }
+
+    fn union_variant_class_name(&self, variant: &Field) -> String {
+        java_name(&variant.attributes).unwrap_or_else(|| capitalize(to_camel_case(variant.name.as_str()).as_str()))
+    }
+
+    fn write_union_imports(&self, content: &mut String) {
+        content.push_str("import me.bottdev.morph.runtime.BinaryWriter;\n");
+        content.push_str("import me.bottdev.morph.runtime.BinaryReader;\n\n");
+        content.push_str("import java.io.ByteArrayOutputStream;\n");
+        content.push_str("import java.io.InputStream;\n");
+        content.push_str("import java.io.IOException;\n");
+        content.push_str("import java.util.Objects;\n");
+    }
+
+    fn write_union_decode_method(&self, content: &mut String, union: &Union, variant_names: &[String]) {
+        let version_arg = if union.version.is_some() {
+            content.push_str(format!(
+                "\tstatic {} decode(InputStream in) throws IOException {{\n",
+                union.name
+            ).as_str());
+            content.push_str("\t\treturn decode(in, VERSION);\n");
+            content.push_str("\t}\n\n");
+
+            content.push_str(format!(
+                "\tstatic {} decode(InputStream in, int protocolVersion) throws IOException {{\n",
+                union.name
+            ).as_str());
+            ", protocolVersion"
+        } else {
+            content.push_str(format!(
+                "\tstatic {} decode(InputStream in) throws IOException {{\n",
+                union.name
+            ).as_str());
+            ""
+        };
+        content.push_str("\t\tbyte tag = BinaryReader.readByte(in);\n");
+        content.push_str("\t\treturn switch (tag) {\n");
+        for variant_name in variant_names {
+            content.push_str(format!(
+                "\t\t\tcase {}.TAG -> {}.decode(in{});\n", variant_name, variant_name, version_arg
+            ).as_str());
+        }
+        content.push_str(format!(
+            "\t\t\tdefault -> throw new IOException(\"Unknown tag \" + tag + \" for union {}\");\n",
+            union.name
+        ).as_str());
+        content.push_str("\t\t};\n");
+        content.push_str("\t}\n\n");
+    }
+
+    fn write_union_variant(&self, content: &mut String, union: &Union, variant: &Field, tag: usize, scope: &JavaScope) {
+
+        let java_type = convert_to_java_type(&variant.typ, scope);
+        let class_name = self.union_variant_class_name(variant);
+        let tag = if tag > i8::MAX as usize { format!("(byte) {}", tag) } else { tag.to_string() };
+
+        self.write_javadoc(content, &self.field_doc(variant, scope), "\t");
+        self.write_deprecated(content, &variant.attributes, "\t");
+        content.push_str(format!("\tfinal class {} implements {} {{\n\n", class_name, union.name).as_str());
+        content.push_str(format!("\t\tpublic static final byte TAG = {};\n\n", tag).as_str());
+        content.push_str(format!("\t\tprivate final {} value;\n\n", java_type).as_str());
+
+        content.push_str(format!("\t\tpublic {}({} value) {{\n", class_name, java_type).as_str());
+        let label = format!("Variant '{}' in {}", variant.name, union.name);
+        self.write_constraint_checks(content, &label, variant, "value", "\t\t\t");
+        content.push_str("\t\t\tthis.value = value;\n");
+        content.push_str("\t\t}\n\n");
+
+        content.push_str(format!("\t\tpublic {} getValue() {{\n", java_type).as_str());
+        content.push_str("\t\t\treturn value;\n");
+        content.push_str("\t\t}\n\n");
+
+        content.push_str("\t\t@Override\n");
+        content.push_str("\t\tpublic byte getTag() {\n");
+        content.push_str("\t\t\treturn TAG;\n");
+        content.push_str("\t\t}\n\n");
+
+        let version_param = match union.version {
+            Some(_) => ", int protocolVersion",
+            None => "",
+        };
+
+        content.push_str("\t\t@Override\n");
+        content.push_str(format!("\t\tpublic void encode(ByteArrayOutputStream out{}) {{\n\n", version_param).as_str());
+        content.push_str("\t\t\tBinaryWriter.writeByte(out, TAG);\n\n");
+        self.write_encode_field(content, "value", &java_type, scope, 2);
+        content.push_str("\t\t}\n\n");
+
+        content.push_str(format!(
+            "\t\tstatic {} decode(InputStream in{}) throws IOException {{\n", class_name, version_param
+        ).as_str());
+        self.write_decode_field(content, "value", &java_type, scope, 1);
+        content.push_str(format!("\t\t\treturn new {}(value);\n", class_name).as_str());
+        content.push_str("\t\t}\n\n");
+
+        if self.options.generate_to_string {
+            content.push_str("\t\t@Override\n");
+            content.push_str("\t\tpublic String toString() {\n");
+            content.push_str(format!(
+                "\t\t\treturn \"{}.{}{{value=\" + {} + '}}';\n",
+                union.name, class_name, to_string_expression(&variant.typ, "value")
+            ).as_str());
+            content.push_str("\t\t}\n\n");
+        }
+
+        if self.options.generate_equals {
+            content.push_str("\t\t@Override\n");
+            content.push_str("\t\tpublic boolean equals(Object o) {\n");
+            content.push_str("\t\t\tif (this == o) return true;\n");
+            content.push_str("\t\t\tif (o == null || getClass() != o.getClass()) return false;\n");
+            content.push_str(format!("\t\t\t{} that = ({}) o;\n", class_name, class_name).as_str());
+            if is_bytes(&variant.typ) {
+                content.push_str("\t\t\treturn Arrays.equals(value, that.value);\n");
+            } else {
+                content.push_str("\t\t\treturn Objects.equals(value, that.value);\n");
+            }
+            content.push_str("\t\t}\n\n");
+        }
+
+        if self.options.generate_hashcode {
+            content.push_str("\t\t@Override\n");
+            content.push_str("\t\tpublic int hashCode() {\n");
+            content.push_str(format!(
+                "\t\t\treturn Objects.hash(TAG, {});\n", hash_expression(&variant.typ, "value")
+            ).as_str());
+            content.push_str("\t\t}\n\n");
+        }
+
+        content.push_str("\t}\n\n");
+
+    }
+
+}
+
+impl JavaGenerator {
+
+    fn generate_constants_class(&self, namespace: &Option<String>, constants: &[&Constant]) -> String {
+
+        let mut content = String::new();
+        let scope = &JavaScope { schema: &Schema::default(), type_names: HashMap::new(), versioned_types: Vec::new() };
+
+        self.write_package(&mut content, namespace);
+        content.push_str("public final class Constants {\n\n");
+
+        for constant in constants {
+            let java_type = convert_to_java_type(&constant.typ, scope);
+            self.write_javadoc(&mut content, &constant.doc, "\t");
+            content.push_str(format!(
+                "\tpublic static final {} {} = {};\n",
+                java_type, constant.name, java_literal(&constant.value, &java_type)
+            ).as_str());
+        }
+
+        content.push_str("\n\tprivate Constants() {}\n\n");
+        content.push('}');
+
+        content
+
+    }
+
+    fn service_type_name(&self, type_name: &str, namespace: &Option<String>, schema: &Schema) -> String {
+        if namespace_of(type_name) == *namespace {
+            java_simple_name(schema, type_name)
+        } else {
+            self.java_class_name(type_name, schema)
+        }
+    }
+
+    fn encode_message(&self, name: &str, type_name: &str, schema: &Schema) -> String {
+        match schema.find_packet(type_name).map(|packet| packet.kind) {
+            Some(PacketKind::Struct) => format!("{}.encode(out)", name),
+            _ => format!("{}.encode(out, false)", name),
+        }
+    }
+
+    fn generate_service(&self, service: &Service, schema: &Schema) -> Vec<(String, String)> {
+        vec![
+            (service.name.clone(), self.generate_service_interface(service, schema)),
+            (format!("{}Client", service.name), self.generate_service_client(service, schema)),
+            (format!("{}Dispatcher", service.name), self.generate_service_dispatcher(service, schema)),
+        ]
+    }
+
+    fn generate_service_interface(&self, service: &Service, schema: &Schema) -> String {
+
+        let mut content = String::new();
+
+        self.write_package(&mut content, &service.namespace);
+        self.write_javadoc(&mut content, &service.doc, "");
+        content.push_str(format!("public interface {} {{
+
+", service.name).as_str());
+
+        for rpc in &service.methods {
+            self.write_javadoc(&mut content, &rpc.doc, "\t");
+            content.push_str(format!(
+                "\t{} {}({} request);\n\n",
+                self.service_type_name(&rpc.response, &service.namespace, schema),
+                to_camel_case(&rpc.name),
+                self.service_type_name(&rpc.request, &service.namespace, schema)
+            ).as_str());
+        }
+
+        content.push('}');
+
+        content
+
+    }
+
+    fn generate_service_client(&self, service: &Service, schema: &Schema) -> String {
+
+        let mut content = String::new();
+        let class_name = format!("{}Client", service.name);
+
+        self.write_package(&mut content, &service.namespace);
+        content.push_str("import me.bottdev.morph.runtime.BinaryReader;\n");
+        content.push_str("import me.bottdev.morph.runtime.BinaryWriter;\n\n");
+        content.push_str("import java.io.ByteArrayInputStream;\n");
+        content.push_str("import java.io.ByteArrayOutputStream;\n");
+        content.push_str("import java.io.IOException;\n");
+        content.push_str("import java.io.InputStream;\n");
+        content.push_str("import java.util.Map;\n");
+        content.push_str("import java.util.concurrent.CompletableFuture;\n");
+        content.push_str("import java.util.concurrent.ConcurrentHashMap;\n");
+        content.push_str("import java.util.concurrent.atomic.AtomicInteger;\n");
+        content.push_str("import java.util.function.Consumer;\n\n");
+
+        content.push_str(format!("public final class {} {{
+
+", class_name).as_str());
+
+        content.push_str("\t@FunctionalInterface\n");
+        content.push_str("\tprivate interface ResponseDecoder<T> {\n");
+        content.push_str("\t\tT decode(InputStream in) throws IOException;\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tprivate record PendingCall<T>(CompletableFuture<T> future, ResponseDecoder<T> decoder) {\n\n");
+        content.push_str("\t\tvoid complete(InputStream in) {\n");
+        content.push_str("\t\t\ttry {\n");
+        content.push_str("\t\t\t\tfuture.complete(decoder.decode(in));\n");
+        content.push_str("\t\t\t} catch (IOException e) {\n");
+        content.push_str("\t\t\t\tfuture.completeExceptionally(e);\n");
+        content.push_str("\t\t\t}\n");
+        content.push_str("\t\t}\n\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tprivate final Consumer<byte[]> sender;\n");
+        content.push_str("\tprivate final AtomicInteger nextRequestId = new AtomicInteger();\n");
+        content.push_str("\tprivate final Map<Integer, PendingCall<?>> pending = new ConcurrentHashMap<>();\n\n");
+
+        content.push_str(format!("\tpublic {}(Consumer<byte[]> sender) {{\n", class_name).as_str());
+        content.push_str("\t\tthis.sender = sender;\n");
+        content.push_str("\t}\n\n");
+
+        for (index, rpc) in service.methods.iter().enumerate() {
+
+            let response = self.service_type_name(&rpc.response, &service.namespace, schema);
+
+            self.write_javadoc(&mut content, &rpc.doc, "\t");
+            content.push_str(format!(
+                "\tpublic CompletableFuture<{}> {}({} request) {{\n",
+                response, to_camel_case(&rpc.name), self.service_type_name(&rpc.request, &service.namespace, schema)
+            ).as_str());
+            content.push_str("\t\tByteArrayOutputStream out = new ByteArrayOutputStream();\n");
+            content.push_str(format!("\t\t{};\n", self.encode_message("request", &rpc.request, schema)).as_str());
+            content.push_str(format!("\t\treturn call((byte) {}, out.toByteArray(), {}::decode);\n", index, response).as_str());
+            content.push_str("\t}\n\n");
+
+        }
+
+        content.push_str("\tpublic void receive(byte[] data) throws IOException {\n");
+        content.push_str("\t\tInputStream in = new ByteArrayInputStream(data);\n");
+        content.push_str("\t\tint requestId = BinaryReader.readInt(in);\n");
+        content.push_str("\t\tPendingCall<?> call = pending.remove(requestId);\n");
+        content.push_str("\t\tif (call == null) throw new IOException(\"Unknown request id \" + requestId);\n");
+        content.push_str("\t\tcall.complete(in);\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tprivate <T> CompletableFuture<T> call(byte method, byte[] request, ResponseDecoder<T> decoder) {\n");
+        content.push_str("\t\tint requestId = nextRequestId.getAndIncrement();\n");
+        content.push_str("\t\tPendingCall<T> call = new PendingCall<>(new CompletableFuture<>(), decoder);\n");
+        content.push_str("\t\tpending.put(requestId, call);\n\n");
+        content.push_str("\t\tByteArrayOutputStream out = new ByteArrayOutputStream();\n");
+        content.push_str("\t\tBinaryWriter.writeInt(out, requestId);\n");
+        content.push_str("\t\tBinaryWriter.writeByte(out, method);\n");
+        content.push_str("\t\tBinaryWriter.writeBytes(out, request);\n");
+        content.push_str("\t\tsender.accept(out.toByteArray());\n\n");
+        content.push_str("\t\treturn call.future();\n");
+        content.push_str("\t}\n\n");
+
+        content.push('}');
+
+        content
+
+    }
+
+    fn generate_service_dispatcher(&self, service: &Service, schema: &Schema) -> String {
+
+        let mut content = String::new();
+        let class_name = format!("{}Dispatcher", service.name);
+
+        self.write_package(&mut content, &service.namespace);
+        content.push_str("import me.bottdev.morph.runtime.BinaryReader;\n");
+        content.push_str("import me.bottdev.morph.runtime.BinaryWriter;\n\n");
+        content.push_str("import java.io.ByteArrayInputStream;\n");
+        content.push_str("import java.io.ByteArrayOutputStream;\n");
+        content.push_str("import java.io.IOException;\n");
+        content.push_str("import java.io.InputStream;\n\n");
+
+        content.push_str(format!("public final class {} {{
+
+", class_name).as_str());
+        content.push_str(format!("\tprivate final {} service;\n\n", service.name).as_str());
+
+        content.push_str(format!("\tpublic {}({} service) {{\n", class_name, service.name).as_str());
+        content.push_str("\t\tthis.service = service;\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tpublic byte[] dispatch(byte[] data) throws IOException {\n");
+        content.push_str("\t\tInputStream in = new ByteArrayInputStream(data);\n");
+        content.push_str("\t\tint requestId = BinaryReader.readInt(in);\n");
+        content.push_str("\t\tint method = Byte.toUnsignedInt(BinaryReader.readByte(in));\n\n");
+        content.push_str("\t\tByteArrayOutputStream out = new ByteArrayOutputStream();\n");
+        content.push_str("\t\tBinaryWriter.writeInt(out, requestId);\n\n");
+
+        content.push_str("\t\tswitch (method) {\n");
+        for (index, rpc) in service.methods.iter().enumerate() {
+            let call = format!(
+                "service.{}({}.decode(in))",
+                to_camel_case(&rpc.name), self.service_type_name(&rpc.request, &service.namespace, schema)
+            );
+            content.push_str(format!("\t\t\tcase {} -> {};\n", index, self.encode_message(&call, &rpc.response, schema)).as_str());
+        }
+        content.push_str(format!(
+            "\t\t\tdefault -> throw new IOException(\"Unknown method \" + method + \" for service {}\");\n",
+            service.name
+        ).as_str());
+        content.push_str("\t\t}\n\n");
+
+        content.push_str("\t\treturn out.toByteArray();\n");
+        content.push_str("\t}\n\n");
+
+        content.push('}');
+
+        content
+
+    }
+
+    fn generate_protocol_state(&self, schema: &Schema) -> String {
+
+        let mut content = String::new();
+
+        self.write_package(&mut content, &None);
+        content.push_str("import me.bottdev.morph.runtime.PacketRegistry;\n\n");
+        content.push_str("public enum ProtocolState {\n\n");
+
+        let constants: Vec<String> = schema.states.iter()
+            .map(|state| format!("\t{}", state.to_uppercase()))
+            .collect();
+        content.push_str(format!("{};\n\n", constants.join(",\n")).as_str());
+
+        content.push_str("\tprivate final PacketRegistry registry = new PacketRegistry();\n");
+        content.push_str("\tprivate final PacketRegistry clientboundRegistry = new PacketRegistry();\n");
+        content.push_str("\tprivate final PacketRegistry serverboundRegistry = new PacketRegistry();\n\n");
+
+        content.push_str("\tstatic {\n");
+        for packet in &schema.packets {
+
+            let Some(state) = &packet.state else {
+                continue;
+            };
+
+            let registry = match packet.direction {
+                Some(Direction::Clientbound) => "clientboundRegistry",
+                Some(Direction::Serverbound) => "serverboundRegistry",
+                None => "registry",
+            };
+            let class_name = self.java_class_name(&packet.qualified_name(), schema);
+
+            content.push_str(format!(
+                "\t\t{}.{}.register({}.PACKET_ID, {}::decode);\n",
+                state.to_uppercase(), registry, class_name, class_name
+            ).as_str());
+
+        }
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tpublic PacketRegistry getRegistry() {\n");
+        content.push_str("\t\treturn registry;\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tpublic PacketRegistry getClientboundRegistry() {\n");
+        content.push_str("\t\treturn clientboundRegistry;\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tpublic PacketRegistry getServerboundRegistry() {\n");
+        content.push_str("\t\treturn serverboundRegistry;\n");
+        content.push_str("\t}\n\n");
+
+        content.push('}');
+
+        content
+
+    }
+
+    fn generate_state_machine(&self, schema: &Schema) -> String {
+
+        let mut content = String::new();
+
+        self.write_package(&mut content, &None);
+        content.push_str("import me.bottdev.morph.runtime.MorphPacket;\n");
+        content.push_str("import me.bottdev.morph.runtime.PacketDecoder;\n");
+        content.push_str("import me.bottdev.morph.runtime.PacketRegistry;\n");
+        content.push_str("import me.bottdev.morph.runtime.decoders.SimplePacketDecoder;\n");
+        content.push_str("import me.bottdev.morph.runtime.exceptions.MorphDecodingException;\n\n");
+        content.push_str("import java.io.InputStream;\n");
+        content.push_str("import java.util.function.Function;\n\n");
+
+        content.push_str("public final class ProtocolStateMachine implements PacketDecoder {\n\n");
+        content.push_str("\tprivate final Function<ProtocolState, PacketRegistry> registries;\n");
+        content.push_str("\tprivate ProtocolState state;\n\n");
+
+        content.push_str("\tpublic ProtocolStateMachine(ProtocolState state) {\n");
+        content.push_str("\t\tthis(state, ProtocolState::getRegistry);\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tpublic ProtocolStateMachine(ProtocolState state, Function<ProtocolState, PacketRegistry> registries) {\n");
+        content.push_str("\t\tthis.state = state;\n");
+        content.push_str("\t\tthis.registries = registries;\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tpublic ProtocolState getState() {\n");
+        content.push_str("\t\treturn state;\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tpublic void setState(ProtocolState state) {\n");
+        content.push_str("\t\tthis.state = state;\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tpublic PacketRegistry getRegistry() {\n");
+        content.push_str("\t\treturn registries.apply(state);\n");
+        content.push_str("\t}\n\n");
+
+        content.push_str("\tpublic void onPacket(MorphPacket packet) {\n");
+        for (i, transition) in schema.transitions.iter().enumerate() {
+            let keyword = if i == 0 { "if" } else { "} else if" };
+            content.push_str(format!(
+                "\t\t{} (state == ProtocolState.{} && packet.getClass() == {}.class) {{\n",
+                keyword, transition.from.to_uppercase(), self.java_class_name(&transition.packet, schema)
+            ).as_str());
+            content.push_str(format!("\t\t\tstate = ProtocolState.{};\n", transition.to.to_uppercase()).as_str());
+        }
+        if !schema.transitions.is_empty() {
+            content.push_str("\t\t}\n");
+        }
+        content.push_str("\t}\n\n");
+
+        content.push_str("\t@Override\n");
+        content.push_str("\tpublic MorphPacket decodeStream(InputStream in) throws MorphDecodingException {\n");
+        content.push_str("\t\tMorphPacket packet = new SimplePacketDecoder(getRegistry()).decodeStream(in);\n");
+        content.push_str("\t\tonPacket(packet);\n");
+        content.push_str("\t\treturn packet;\n");
+        content.push_str("\t}\n\n");
+
+        content.push('}');
+
+        content
+
+    }
+
+    fn create_namespace_dir(&self, output_dir: &Path, namespace: &Option<String>) -> MorphResult<PathBuf> {
+
+        let mut dir = output_dir.to_path_buf();
+
+        if let Some(namespace) = namespace {
+            dir.extend(namespace.split('.'));
+        }
+
+        if let Err(err) = std::fs::create_dir_all(&dir) {
+            let morph_err = GenerationError {
+                message: format!("Failed to create directory {}: {}", dir.to_string_lossy(), err),
+            };
+            return Errors(vec![Box::new(morph_err)]);
+        }
+
+        Success(dir)
+    }
+
+}
+
+impl Generator for JavaGenerator {
+
+    fn generate(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
+
+        let output_dir = Path::new(&self.options.output_dir);
+
+        if let Err(err) = std::fs::create_dir_all(output_dir) {
+            let morph_err = GenerationError {
+                message: format!("Failed to create output directory: {}", err.to_string()),
+            };
+            return Errors(vec![Box::new(morph_err)]);
+        }
+
+        for enum_decl in &schema.enums {
+
+            let content = match enum_decl.kind {
+                EnumKind::Enum => self.generate_enum(enum_decl, context),
+                EnumKind::Flags => self.generate_flags(enum_decl, context),
+            };
+            let path = match self.create_namespace_dir(output_dir, &enum_decl.namespace) {
+                Success(dir) => dir.join(format!("{}.java", enum_decl.name)),
+                Errors(errors) => return Errors(errors),
+            };
+
+            if let Err(err) = std::fs::write(&path, content) {
+                let morph_err = GenerationError {
+                    message: format!("Failed to write {}.java file: {}", enum_decl.name, err),
+                };
+                return Errors(vec![Box::new(morph_err)]);
+            }
+
+        }
+
+        for union in &schema.unions {
+
+            let content = self.generate_union(union, context);
+            let path = match self.create_namespace_dir(output_dir, &union.namespace) {
+                Success(dir) => dir.join(format!("{}.java", union.name)),
+                Errors(errors) => return Errors(errors),
+            };
+
+            if let Err(err) = std::fs::write(&path, content) {
+                let morph_err = GenerationError {
+                    message: format!("Failed to write {}.java file: {}", union.name, err),
+                };
+                return Errors(vec![Box::new(morph_err)]);
+            }
+
+        }
+
+        for packet in &schema.packets {
+
+            let content = self.generate_class(packet, context);
+            let path = match self.create_namespace_dir(output_dir, &packet.namespace) {
+                Success(dir) => dir.join(format!("{}.java", packet_class_name(packet))),
+                Errors(errors) => return Errors(errors),
+            };
+
+            match std::fs::write(&path, content) {
+                Ok(_) => {}
+                Err(err) => {
+                    let morph_err = GenerationError {
+                        message: format!("Failed to write {}.java file: {}", packet_class_name(packet), err),
+                    };
+                    return Errors(vec![Box::new(morph_err)]);
+                }
+            }
+
+
+        }
+
+        for service in &schema.services {
+            for (class_name, content) in self.generate_service(service, context) {
+
+                let path = match self.create_namespace_dir(output_dir, &service.namespace) {
+                    Success(dir) => dir.join(format!("{}.java", class_name)),
+                    Errors(errors) => return Errors(errors),
+                };
+
+                if let Err(err) = std::fs::write(&path, content) {
+                    let morph_err = GenerationError {
+                        message: format!("Failed to write {}.java file: {}", class_name, err),
+                    };
+                    return Errors(vec![Box::new(morph_err)]);
+                }
+
+            }
+        }
+
+        Success(())
+
+    }
+
+    fn generate_constants(&self, schema: &Schema) -> MorphResult<()> {
+
+        let output_dir = Path::new(&self.options.output_dir);
+        let mut namespaces: Vec<Option<String>> = Vec::new();
+
+        for constant in &schema.constants {
+            if !namespaces.contains(&constant.namespace) {
+                namespaces.push(constant.namespace.clone());
+            }
+        }
+
+        for namespace in &namespaces {
+
+            let constants: Vec<&Constant> = schema.constants.iter()
+                .filter(|constant| constant.namespace == *namespace)
+                .collect();
+
+            let content = self.generate_constants_class(namespace, &constants);
+            let path = match self.create_namespace_dir(output_dir, namespace) {
+                Success(dir) => dir.join("Constants.java"),
+                Errors(errors) => return Errors(errors),
+            };
+
+            if let Err(err) = std::fs::write(&path, content) {
+                let morph_err = GenerationError {
+                    message: format!("Failed to write Constants.java file: {}", err),
+                };
+                return Errors(vec![Box::new(morph_err)]);
+            }
+
+        }
+
+        Success(())
+
+    }
+
+    fn generate_states(&self, schema: &Schema) -> MorphResult<()> {
+
+        let output_dir = Path::new(&self.options.output_dir);
+
+        let files = [
+            ("ProtocolState", self.generate_protocol_state(schema)),
+            ("ProtocolStateMachine", self.generate_state_machine(schema)),
+        ];
+
+        for (class_name, content) in files {
+
+            let path = match self.create_namespace_dir(output_dir, &None) {
+                Success(dir) => dir.join(format!("{}.java", class_name)),
+                Errors(errors) => return Errors(errors),
+            };
+
+            if let Err(err) = std::fs::write(&path, content) {
+                let morph_err = GenerationError {
+                    message: format!("Failed to write {}.java file: {}", class_name, err),
+                };
+                return Errors(vec![Box::new(morph_err)]);
+            }
+
+        }
+
+        Success(())
+
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::test_utils::parse_schema;
+
+    fn generator() -> JavaGenerator {
+        let options = toml::from_str("output_dir = \"out\"\npackage = \"packets\"").unwrap();
+        JavaGenerator { options }
+    }
+
+    #[test]
+    fn casts_u32_default_above_int_range() {
+
+        let schema = parse_schema(r#"
+            packet Big {
+                id = 1
+                fields {
+                    u32 small = 7
+                    u32 value = 4000000000
+                }
+            }
+        "#);
+
+        let content = generator().generate_class(&schema.packets[0], &schema);
+
+        assert!(content.contains("this.small = 7;"), "{}", content);
+        assert!(content.contains("this.value = (int) 4000000000L;"), "{}", content);
+    }
+
+    #[test]
+    fn casts_u32_constant_above_int_range() {
+
+        let schema = parse_schema(r#"
+            const SMALL: u32 = 7;
+            const BIG: u32 = 4000000000;
+        "#);
+
+        let constants: Vec<&Constant> = schema.constants.iter().collect();
+        let content = generator().generate_constants_class(&None, &constants);
+
+        assert!(content.contains("public static final int SMALL = 7;"), "{}", content);
+        assert!(content.contains("public static final int BIG = (int) 4000000000L;"), "{}", content);
+    }
+
+}
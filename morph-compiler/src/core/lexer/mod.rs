@@ -3,5 +3,5 @@ pub mod token;
 pub mod simple_lexer;
 
 pub use lexer::Lexer;
-pub use token::{Token, TokenKind, FieldType, OperatorKind, as_token_kind};
+pub use token::{Token, TokenKind, FieldType, ArraySize, OperatorKind, as_token_kind};
 pub use simple_lexer::SimpleLexer;
\ No newline at end of file
@@ -1,7 +1,7 @@
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use crate::core::Lexer;
-use crate::core::Token;
+use crate::core::{Token, TokenKind};
 use crate::core::as_token_kind;
 use crate::core::lexer::lexer::{LexerError, ReadError};
 use crate::utils::{FileWrapper, MorphError, MorphResult};
@@ -9,13 +9,18 @@ use crate::utils::MorphResult::{Errors, Success};
 
 pub struct SimpleLexer;
 
+#[derive(Default)]
+struct LexerState {
+    block_comment_start: Option<(usize, usize)>,
+}
+
 impl SimpleLexer {
 
     fn is_special_char(ch: char) -> bool {
-        matches!(ch, '{' | '}' | '=' | ',' | ':' | ';' | '(' | ')' | '[' | ']')
+        matches!(ch, '{' | '}' | '=' | ',' | ':' | ';' | '(' | ')' | '[' | ']' | '<' | '>' | '@')
     }
 
-    fn tokenize_line(&self, line_number: usize, line: &str) -> MorphResult<Vec<Token>> {
+    fn tokenize_line(&self, state: &mut LexerState, line_number: usize, line: &str) -> MorphResult<Vec<Token>> {
         let mut tokens: Vec<Token> = Vec::new();
         let mut errors: Vec<Box<dyn MorphError>> = Vec::new();
 
@@ -25,6 +30,73 @@ impl SimpleLexer {
 
         while let Some((idx, ch)) = chars.next() {
 
+            if state.block_comment_start.is_some() {
+
+                if ch == '*' && matches!(chars.peek(), Some((_, '/'))) {
+                    chars.next();
+                    state.block_comment_start = None;
+                }
+
+                continue;
+            }
+
+            if ch == '/' && matches!(chars.peek(), Some((_, '/') | (_, '*'))) {
+
+                if !current_word.is_empty() {
+                    self.process_word(current_word.as_str(), line_number, word_start_column, &mut tokens, &mut errors);
+                    current_word.clear();
+                }
+
+                if let Some((_, '*')) = chars.next() {
+                    state.block_comment_start = Some((line_number, idx));
+                    continue;
+                }
+
+                if let Some((_, '/')) = chars.peek() {
+                    chars.next();
+                    if matches!(chars.peek(), Some((_, '/'))) {
+                        break;
+                    }
+                    let text: String = chars.by_ref().map(|(_, ch)| ch).collect();
+                    let text = text.strip_prefix(' ').unwrap_or(text.as_str()).trim_end();
+                    tokens.push(Token {
+                        kind: TokenKind::DocComment(text.to_string()),
+                        line: line_number,
+                        column: idx,
+                    });
+                }
+
+                break;
+            }
+
+            if ch == '"' {
+
+                if !current_word.is_empty() {
+                    self.process_word(current_word.as_str(), line_number, word_start_column, &mut tokens, &mut errors);
+                    current_word.clear();
+                }
+
+                match self.read_string_literal(&mut chars) {
+                    Some(value) => {
+                        tokens.push(Token {
+                            kind: TokenKind::StringLiteral(value),
+                            line: line_number,
+                            column: idx,
+                        });
+                    }
+                    None => {
+                        let err = LexerError {
+                            message: "Unterminated string literal".to_string(),
+                            line: line_number,
+                            column: idx,
+                        };
+                        errors.push(Box::new(err));
+                    }
+                }
+
+                continue;
+            }
+
             if ch.is_whitespace() {
 
                 if !current_word.is_empty() {
@@ -35,6 +107,32 @@ impl SimpleLexer {
                 continue;
             }
 
+            if ch == '-' && matches!(chars.peek(), Some((_, '>'))) {
+
+                if !current_word.is_empty() {
+                    self.process_word(current_word.as_str(), line_number, word_start_column, &mut tokens, &mut errors);
+                    current_word.clear();
+                }
+
+                chars.next();
+                self.process_word("->", line_number, idx, &mut tokens, &mut errors);
+                continue;
+
+            }
+
+            if ch == '.' && matches!(chars.peek(), Some((_, '.'))) {
+
+                if !current_word.is_empty() {
+                    self.process_word(current_word.as_str(), line_number, word_start_column, &mut tokens, &mut errors);
+                    current_word.clear();
+                }
+
+                chars.next();
+                self.process_word("..", line_number, idx, &mut tokens, &mut errors);
+                continue;
+
+            }
+
             if Self::is_special_char(ch) {
 
                 if !current_word.is_empty() {
@@ -66,6 +164,76 @@ impl SimpleLexer {
         }
     }
 
+    fn read_string_literal<I>(&self, chars: &mut I) -> Option<String>
+    where
+        I: Iterator<Item = (usize, char)>,
+    {
+        let mut value = String::new();
+
+        while let Some((_, ch)) = chars.next() {
+            match ch {
+                '"' => return Some(value),
+                '\\' => match chars.next() {
+                    Some((_, 'n')) => value.push('\n'),
+                    Some((_, 't')) => value.push('\t'),
+                    Some((_, escaped)) => value.push(escaped),
+                    None => return None,
+                },
+                _ => value.push(ch),
+            }
+        }
+
+        None
+    }
+
+    fn tokenize_lines<I>(&self, lines: I) -> MorphResult<Vec<Token>>
+    where
+        I: Iterator<Item = std::io::Result<String>>,
+    {
+
+        let mut tokens: Vec<Token> = Vec::new();
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+        let mut state = LexerState::default();
+
+        for (line_number, result) in lines.enumerate() {
+            match result {
+                Ok(line) => {
+                    match self.tokenize_line(&mut state, line_number + 1, line.as_str()) {
+                        Success(line_tokens) => { tokens.extend(line_tokens) }
+                        Errors(errors) => { all_errors.extend(errors) }
+                    }
+                }
+                Err(err) => {
+                    let read_error = ReadError {
+                        message: format!("Failed to read line {}, cause: {}", line_number, err)
+                    };
+                    all_errors.push(Box::new(read_error));
+                }
+            }
+        }
+
+        if let Some((line, column)) = state.block_comment_start {
+            let err = LexerError {
+                message: "Unterminated block comment".to_string(),
+                line,
+                column,
+            };
+            all_errors.push(Box::new(err));
+        }
+
+        if all_errors.is_empty() {
+            Success(tokens)
+        } else {
+            Errors(all_errors)
+        }
+
+    }
+
+    #[cfg(test)]
+    pub fn tokenize_str(&self, source: &str) -> MorphResult<Vec<Token>> {
+        self.tokenize_lines(source.lines().map(|line| Ok(line.to_string())))
+    }
+
     fn process_word(
         &self,
         word: &str,
@@ -99,45 +267,74 @@ impl Lexer for SimpleLexer {
 
     fn tokenize(&self, wrapper: &mut FileWrapper) -> MorphResult<Vec<Token>> {
 
-        let mut tokens: Vec<Token> = Vec::new();
-        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
-
         let file = match File::open(&wrapper.path) {
             Ok(file) => file,
             Err(err) => {
                 let morph_error = ReadError {
                     message: format!("Failed to open file {}: {}", &wrapper.path.to_string_lossy(), err)
                 };
-                all_errors.push(Box::new(morph_error));
-                return Errors(all_errors);
+                return Errors(vec![Box::new(morph_error)]);
             }
         };
 
-        let reader = BufReader::new(file);
+        self.tokenize_lines(BufReader::new(file).lines())
+    }
 
-        for (line_number, result) in reader.lines().enumerate() {
-            match result {
-                Ok(line) => {
-                    match self.tokenize_line(line_number + 1, line.as_str()) {
-                        Success(line_tokens) => { tokens.extend(line_tokens) }
-                        Errors(errors) => { all_errors.extend(errors) }
-                    }
-                }
-                Err(err) => {
-                    let read_error = ReadError {
-                        message: format!("Failed to read line {}, cause: {}", line_number, err)
-                    };
-                    all_errors.push(Box::new(read_error));
-                }
-            }
-        }
+}
 
-        if all_errors.is_empty() {
-            Success(tokens)
-        } else {
-            Errors(all_errors)
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::core::token::KeywordKind;
+    use crate::utils::test_utils::error_messages;
+
+    fn tokens(source: &str) -> Vec<(TokenKind, usize, usize)> {
+        match SimpleLexer.tokenize_str(source) {
+            Success(tokens) => tokens.into_iter().map(|token| (token.kind, token.line, token.column)).collect(),
+            Errors(errors) => panic!("{:?}", error_messages::<()>(Errors(errors))),
         }
+    }
+
+    fn qualifier(name: &str) -> TokenKind {
+        TokenKind::Qualifier(name.to_string())
+    }
+
+    #[test]
+    fn skips_line_comments() {
+
+        assert_eq!(tokens("// unused since v2\npacket Login // trailing\n{"), vec![
+            (TokenKind::Keyword(KeywordKind::Packet), 2, 0),
+            (qualifier("Login"), 2, 7),
+            (TokenKind::BraceOpen, 3, 0),
+        ]);
+    }
+
+    #[test]
+    fn skips_block_comments_across_lines() {
 
+        assert_eq!(tokens("a /* one\ntwo\nthree */ b /* c */ d"), vec![
+            (qualifier("a"), 1, 0),
+            (qualifier("b"), 3, 9),
+            (qualifier("d"), 3, 19),
+        ]);
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn rejects_unterminated_block_comment() {
+
+        let errors = error_messages(SimpleLexer.tokenize_str("a\n  /* never\nclosed"));
+
+        assert_eq!(errors, vec!["LexerError at line 2 column 2: Unterminated block comment"]);
+    }
+
+    #[test]
+    fn distinguishes_doc_comments_from_line_comments() {
+
+        assert_eq!(tokens("/// doc\n// line\n//// plain\na"), vec![
+            (TokenKind::DocComment("doc".to_string()), 1, 0),
+            (qualifier("a"), 4, 0),
+        ]);
+    }
+
+}
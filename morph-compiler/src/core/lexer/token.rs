@@ -13,8 +13,22 @@ pub enum TokenKind {
     Operator(OperatorKind),
     BraceOpen,
     BraceClose,
+    BracketOpen,
+    BracketClose,
+    ParenOpen,
+    ParenClose,
+    AngleOpen,
+    AngleClose,
+    At,
+    Colon,
+    Comma,
+    Semicolon,
     Qualifier(String),
-    Number(i32)
+    Number(i64),
+    FloatLiteral(f64),
+    BoolLiteral(bool),
+    StringLiteral(String),
+    DocComment(String),
 }
 
 impl Display for TokenKind {
@@ -24,8 +38,22 @@ impl Display for TokenKind {
             TokenKind::Operator(kind) => write!(f, "{}", kind),
             TokenKind::BraceOpen => write!(f, "{{"),
             TokenKind::BraceClose => write!(f, "}}"),
+            TokenKind::BracketOpen => write!(f, "["),
+            TokenKind::BracketClose => write!(f, "]"),
+            TokenKind::ParenOpen => write!(f, "("),
+            TokenKind::ParenClose => write!(f, ")"),
+            TokenKind::AngleOpen => write!(f, "<"),
+            TokenKind::AngleClose => write!(f, ">"),
+            TokenKind::At => write!(f, "@"),
+            TokenKind::Colon => write!(f, ":"),
+            TokenKind::Comma => write!(f, ","),
+            TokenKind::Semicolon => write!(f, ";"),
             TokenKind::Qualifier(qualifier) => write!(f, "{}", qualifier),
             TokenKind::Number(number) => write!(f, "{}", number),
+            TokenKind::FloatLiteral(value) => write!(f, "{:?}", value),
+            TokenKind::BoolLiteral(value) => write!(f, "{}", value),
+            TokenKind::StringLiteral(value) => write!(f, "{:?}", value),
+            TokenKind::DocComment(text) => write!(f, "/// {}", text),
         }
     }
 }
@@ -33,17 +61,29 @@ impl Display for TokenKind {
 #[derive(Debug, Clone, PartialEq)]
 pub enum KeywordKind {
     Packet,
+    Struct,
+    Union,
+    Enum,
+    Const,
+    Import,
+    Namespace,
     Identifier,
     Auto,
     Fields,
     FieldDefinition(FieldType),
-    Array
+    Array,
 }
 
 impl Display for KeywordKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             KeywordKind::Packet => write!(f, "packet"),
+            KeywordKind::Struct => write!(f, "struct"),
+            KeywordKind::Union => write!(f, "union"),
+            KeywordKind::Enum => write!(f, "enum"),
+            KeywordKind::Const => write!(f, "const"),
+            KeywordKind::Import => write!(f, "import"),
+            KeywordKind::Namespace => write!(f, "namespace"),
             KeywordKind::Identifier => write!(f, "id"),
             KeywordKind::Auto => write!(f, "auto"),
             KeywordKind::Fields => write!(f, "fields"),
@@ -56,12 +96,42 @@ impl Display for KeywordKind {
 #[derive(Debug, Clone, PartialEq)]
 pub enum OperatorKind {
     Equals,
+    Arrow,
+    Range,
 }
 
 impl Display for OperatorKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             OperatorKind::Equals => write!(f, "="),
+            OperatorKind::Arrow => write!(f, "->"),
+            OperatorKind::Range => write!(f, ".."),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArraySize {
+    Value(u32),
+    Reference(String),
+}
+
+impl ArraySize {
+
+    pub fn value(&self) -> Option<u32> {
+        match self {
+            ArraySize::Value(value) => Some(*value),
+            ArraySize::Reference(_) => None,
+        }
+    }
+
+}
+
+impl Display for ArraySize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArraySize::Value(value) => write!(f, "{}", value),
+            ArraySize::Reference(name) => write!(f, "{}", name),
         }
     }
 }
@@ -86,9 +156,55 @@ pub enum FieldType {
 
     Char,
     Str,
+    Bytes,
 
     Array(Box<FieldType>),
+    FixedArray(Box<FieldType>, ArraySize),
+    Set(Box<FieldType>),
+    Optional(Box<FieldType>),
+    Map(Box<FieldType>, Box<FieldType>),
     Nested(String),
+    Union(String),
+    Generic(String, Vec<FieldType>),
+
+}
+
+impl FieldType {
+
+    pub fn is_primitive(&self) -> bool {
+        matches!(
+            self,
+            FieldType::Bool |
+            FieldType::I8 | FieldType::I16 | FieldType::I32 | FieldType::I64 |
+            FieldType::U8 | FieldType::U16 | FieldType::U32 | FieldType::U64 |
+            FieldType::F32 | FieldType::F64 |
+            FieldType::Char
+        )
+    }
+
+    pub fn integer_range(&self) -> Option<(i64, i64)> {
+        match self {
+            FieldType::I8 => Some((i8::MIN as i64, i8::MAX as i64)),
+            FieldType::I16 => Some((i16::MIN as i64, i16::MAX as i64)),
+            FieldType::I32 => Some((i32::MIN as i64, i32::MAX as i64)),
+            FieldType::I64 => Some((i64::MIN, i64::MAX)),
+            FieldType::U8 => Some((0, u8::MAX as i64)),
+            FieldType::U16 => Some((0, u16::MAX as i64)),
+            FieldType::U32 => Some((0, u32::MAX as i64)),
+            FieldType::U64 => Some((0, i64::MAX)),
+            _ => None,
+        }
+    }
+
+    pub fn bit_width(&self) -> Option<u32> {
+        match self {
+            FieldType::I8 | FieldType::U8 => Some(8),
+            FieldType::I16 | FieldType::U16 => Some(16),
+            FieldType::I32 | FieldType::U32 => Some(32),
+            FieldType::I64 | FieldType::U64 => Some(64),
+            _ => None,
+        }
+    }
 
 }
 
@@ -112,9 +228,19 @@ impl Display for FieldType {
 
             FieldType::Char => write!(f, "char"),
             FieldType::Str => write!(f, "str"),
+            FieldType::Bytes => write!(f, "bytes"),
 
             FieldType::Array(array_type) => write!(f, "array[{}]", array_type),
+            FieldType::FixedArray(array_type, size) => write!(f, "array[{}; {}]", array_type, size),
+            FieldType::Set(element_type) => write!(f, "set[{}]", element_type),
+            FieldType::Optional(inner_type) => write!(f, "optional[{}]", inner_type),
+            FieldType::Map(key_type, value_type) => write!(f, "map[{}, {}]", key_type, value_type),
             FieldType::Nested(name) => write!(f, "{}", name),
+            FieldType::Union(name) => write!(f, "{}", name),
+            FieldType::Generic(name, args) => {
+                let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+                write!(f, "{}<{}>", name, args.join(", "))
+            }
 
         }
     }
@@ -126,11 +252,29 @@ pub fn as_token_kind(content: &str) -> Option<TokenKind> {
     match content {
         //keywords
         "packet" => Some(TokenKind::Keyword(KeywordKind::Packet)),
+        "struct" => Some(TokenKind::Keyword(KeywordKind::Struct)),
+        "union" => Some(TokenKind::Keyword(KeywordKind::Union)),
+        "enum" => Some(TokenKind::Keyword(KeywordKind::Enum)),
+        "const" => Some(TokenKind::Keyword(KeywordKind::Const)),
+        "import" => Some(TokenKind::Keyword(KeywordKind::Import)),
+        "namespace" => Some(TokenKind::Keyword(KeywordKind::Namespace)),
         "id" => Some(TokenKind::Keyword(KeywordKind::Identifier)),
         "auto" => Some(TokenKind::Keyword(KeywordKind::Auto)),
         "=" => Some(TokenKind::Operator(OperatorKind::Equals)),
+        "->" => Some(TokenKind::Operator(OperatorKind::Arrow)),
+        ".." => Some(TokenKind::Operator(OperatorKind::Range)),
         "{" => Some(TokenKind::BraceOpen),
         "}" => Some(TokenKind::BraceClose),
+        "[" => Some(TokenKind::BracketOpen),
+        "]" => Some(TokenKind::BracketClose),
+        "(" => Some(TokenKind::ParenOpen),
+        ")" => Some(TokenKind::ParenClose),
+        "<" => Some(TokenKind::AngleOpen),
+        ">" => Some(TokenKind::AngleClose),
+        "@" => Some(TokenKind::At),
+        ":" => Some(TokenKind::Colon),
+        "," => Some(TokenKind::Comma),
+        ";" => Some(TokenKind::Semicolon),
         "fields" => Some(TokenKind::Keyword(KeywordKind::Fields)),
         "array" => Some(TokenKind::Keyword(KeywordKind::Array)),
 
@@ -157,13 +301,24 @@ pub fn as_token_kind(content: &str) -> Option<TokenKind> {
         "char" => Some(TokenKind::Keyword(KeywordKind::FieldDefinition(Char))),
         "string" | "str" => Some(TokenKind::Keyword(KeywordKind::FieldDefinition(Str))),
 
+        //boolean literals
+        "true" => Some(TokenKind::BoolLiteral(true)),
+        "false" => Some(TokenKind::BoolLiteral(false)),
+
         //numbers and qualifiers
         _ => {
-            if let Ok(num) = content.parse::<i32>() {
+            if let Ok(num) = content.parse::<i64>() {
                 Some(TokenKind::Number(num))
+            } else if is_float_literal(content) {
+                content.parse::<f64>().ok().map(TokenKind::FloatLiteral)
             } else {
                 Some(TokenKind::Qualifier(content.to_string()))
             }
         }
     }
 }
+
+fn is_float_literal(content: &str) -> bool {
+    let digits = content.strip_prefix('-').unwrap_or(content);
+    digits.starts_with(|ch: char| ch.is_ascii_digit()) && content.parse::<f64>().is_ok()
+}
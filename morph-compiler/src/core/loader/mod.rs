@@ -0,0 +1,3 @@
+mod schema_loader;
+
+pub use schema_loader::SchemaLoader;
\ No newline at end of file
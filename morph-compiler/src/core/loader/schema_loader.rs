@@ -0,0 +1,182 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use crate::core::{AstParser, Lexer, Schema, Token};
+use crate::utils::{FileWrapper, MorphError, MorphResult};
+use crate::utils::MorphResult::{Errors, Success};
+
+#[derive(Debug)]
+pub struct ImportError {
+    pub message: String,
+}
+
+impl Display for ImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ImportError: {}", self.message)
+    }
+}
+
+impl MorphError for ImportError {
+    fn message(&self) -> String {
+        format!("ImportError: {}", self.message)
+    }
+}
+
+struct LoadedFile {
+    schema: Schema,
+    imports: Vec<PathBuf>,
+}
+
+pub struct SchemaLoader<'a> {
+    lexer: &'a dyn Lexer,
+    parser: &'a dyn AstParser,
+    files: HashMap<PathBuf, LoadedFile>,
+}
+
+impl<'a> SchemaLoader<'a> {
+
+    pub fn new(lexer: &'a dyn Lexer, parser: &'a dyn AstParser) -> SchemaLoader<'a> {
+        SchemaLoader { lexer, parser, files: HashMap::new() }
+    }
+
+    pub fn canonicalize(path: &Path) -> MorphResult<PathBuf> {
+        match path.canonicalize() {
+            Ok(path) => Success(path),
+            Err(err) => {
+                let morph_error = ImportError {
+                    message: format!("Failed to resolve file {}: {}", path.to_string_lossy(), err),
+                };
+                Errors(vec![Box::new(morph_error)])
+            }
+        }
+    }
+
+    pub fn load(&mut self, path: &Path) -> MorphResult<()> {
+
+        let path = match Self::canonicalize(path) {
+            Success(path) => path,
+            Errors(errors) => return Errors(errors),
+        };
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+        let mut pending: Vec<PathBuf> = vec![path.clone()];
+
+        while let Some(current) = pending.pop() {
+
+            if self.files.contains_key(&current) {
+                continue;
+            }
+
+            match self.load_file(&current) {
+                Success(file) => {
+                    pending.extend(file.imports.iter().cloned());
+                    self.files.insert(current, file);
+                }
+                Errors(errors) => {
+                    if current != path {
+                        let morph_error = ImportError {
+                            message: format!("Failed to load imported file {}", current.to_string_lossy()),
+                        };
+                        all_errors.push(Box::new(morph_error));
+                    }
+                    all_errors.extend(errors)
+                }
+            }
+
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+        } else {
+            Errors(all_errors)
+        }
+
+    }
+
+    fn load_file(&self, path: &Path) -> MorphResult<LoadedFile> {
+
+        let mut wrapper = FileWrapper {
+            path: path.to_path_buf(),
+        };
+
+        let tokens: Vec<Token> = match self.lexer.tokenize(&mut wrapper) {
+            Success(tokens) => tokens,
+            Errors(errors) => return Errors(errors),
+        };
+
+        let schema = match self.parser.parse(&tokens) {
+            Success(schema) => schema,
+            Errors(errors) => return Errors(errors),
+        };
+
+        let mut imports: Vec<PathBuf> = Vec::new();
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+        let base_dir = path.parent().unwrap_or(Path::new(""));
+
+        for import in &schema.imports {
+            match base_dir.join(&import.path).canonicalize() {
+                Ok(import_path) => imports.push(import_path),
+                Err(err) => {
+                    let morph_error = ImportError {
+                        message: format!(
+                            "Failed to import '{}' at line {} of {}: {}",
+                            import.path, import.token.line, path.to_string_lossy(), err
+                        ),
+                    };
+                    all_errors.push(Box::new(morph_error));
+                }
+            }
+        }
+
+        if all_errors.is_empty() {
+            Success(LoadedFile { schema, imports })
+        } else {
+            Errors(all_errors)
+        }
+
+    }
+
+    pub fn schema(&self, path: &Path) -> Option<&Schema> {
+        self.files.get(path).map(|file| &file.schema)
+    }
+
+    pub fn imported_paths(&self, path: &Path) -> Vec<PathBuf> {
+
+        let mut visited: HashSet<PathBuf> = HashSet::new();
+        let mut result: Vec<PathBuf> = Vec::new();
+        let mut pending: Vec<PathBuf> = vec![path.to_path_buf()];
+
+        visited.insert(path.to_path_buf());
+
+        while let Some(current) = pending.pop() {
+            if let Some(file) = self.files.get(&current) {
+                for import in &file.imports {
+                    if visited.insert(import.clone()) {
+                        result.push(import.clone());
+                        pending.push(import.clone());
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    pub fn merged_schema(&self, path: &Path) -> Schema {
+
+        let mut merged = Schema::default();
+
+        if let Some(schema) = self.schema(path) {
+            merged.merge(schema);
+        }
+
+        for import in self.imported_paths(path) {
+            if let Some(schema) = self.schema(&import) {
+                merged.merge(schema);
+            }
+        }
+
+        merged
+    }
+
+}
@@ -2,8 +2,10 @@ mod lexer;
 mod parser;
 mod semantics;
 mod generator;
+mod loader;
 
 pub use lexer::*;
 pub use parser::*;
 pub use semantics::*;
-pub use generator::*;
\ No newline at end of file
+pub use generator::*;
+pub use loader::*;
\ No newline at end of file
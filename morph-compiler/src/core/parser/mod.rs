@@ -1,5 +1,5 @@
 mod parser;
 mod simple_parser;
 
-pub use parser::{AstParser, Packet, Field, ParserError};
+pub use parser::{AstParser, Schema, Import, Packet, PacketKind, Direction, Transition, Field, Constraint, ConstraintKind, Attribute, AttributeArg, Literal, Enum, EnumKind, EnumVariant, Constant, TypeAlias, Union, Service, Rpc, Reserved, ParserError, qualify_name, find_attribute};
 pub use simple_parser::SimpleParser;
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
 use crate::core::{FieldType, Token};
-use crate::utils::{MorphError, MorphResult};
+use crate::utils::{hash_str_to_i8_positive, MorphError, MorphResult};
 
 #[derive(Debug)]
 pub struct ParserError {
@@ -34,21 +35,447 @@ impl MorphError for ParserError {
     }
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    pub namespace: Option<String>,
+    pub version: Option<u32>,
+    pub imports: Vec<Import>,
+    pub packets: Vec<Packet>,
+    pub templates: Vec<Packet>,
+    pub enums: Vec<Enum>,
+    pub constants: Vec<Constant>,
+    pub aliases: Vec<TypeAlias>,
+    pub unions: Vec<Union>,
+    pub states: Vec<String>,
+    pub transitions: Vec<Transition>,
+    pub services: Vec<Service>,
+    pub reserved: Reserved,
+}
+
+impl Schema {
+
+    pub fn merge(&mut self, other: &Schema) {
+        self.packets.extend(other.packets.iter().cloned());
+        self.templates.extend(other.templates.iter().cloned());
+        self.enums.extend(other.enums.iter().cloned());
+        self.constants.extend(other.constants.iter().cloned());
+        self.aliases.extend(other.aliases.iter().cloned());
+        self.unions.extend(other.unions.iter().cloned());
+        self.states.extend(other.states.iter().cloned());
+        self.transitions.extend(other.transitions.iter().cloned());
+        self.services.extend(other.services.iter().cloned());
+        self.reserved.ids.extend(other.reserved.ids.iter().cloned());
+        self.reserved.names.extend(other.reserved.names.iter().cloned());
+    }
+
+    pub fn find_packet(&self, name: &str) -> Option<&Packet> {
+        self.packets.iter().find(|p| p.qualified_name() == name)
+    }
+
+    pub fn find_template(&self, name: &str) -> Option<&Packet> {
+        self.templates.iter().find(|t| t.qualified_name() == name)
+    }
+
+    pub fn find_enum(&self, name: &str) -> Option<&Enum> {
+        self.enums.iter().find(|e| e.qualified_name() == name)
+    }
+
+    pub fn ancestors(&self, packet: &Packet) -> Vec<&Packet> {
+
+        let mut ancestors: Vec<&Packet> = Vec::new();
+        let mut current = packet;
+
+        while let Some(parent) = current.parent.as_ref().and_then(|parent| self.find_packet(parent)) {
+            if parent.qualified_name() == packet.qualified_name() || ancestors.iter().any(|a| a.qualified_name() == parent.qualified_name()) {
+                break;
+            }
+            ancestors.push(parent);
+            current = parent;
+        }
+
+        ancestors.reverse();
+        ancestors
+    }
+
+    pub fn all_fields(&self, packet: &Packet) -> Vec<Field> {
+        self.ancestors(packet).iter()
+            .flat_map(|ancestor| ancestor.fields.iter().cloned())
+            .chain(packet.fields.iter().cloned())
+            .collect()
+    }
+
+}
+
+pub fn qualify_name(namespace: &Option<String>, name: &str) -> String {
+    match namespace {
+        Some(namespace) => format!("{}.{}", namespace, name),
+        None => name.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Reserved {
+    pub ids: Vec<RangeInclusive<i32>>,
+    pub names: Vec<String>,
+}
+
+impl Reserved {
+
+    pub fn is_id_reserved(&self, id: i32) -> bool {
+        self.ids.iter().any(|range| range.contains(&id))
+    }
+
+    pub fn is_name_reserved(&self, name: &str) -> bool {
+        self.names.iter().any(|reserved| reserved == name)
+    }
+
+    pub fn auto_id(&self, qualified_name: &str) -> Option<i32> {
+
+        let hashed = hash_str_to_i8_positive(qualified_name) as i32;
+
+        (0..=i8::MAX as i32)
+            .map(|offset| (hashed + offset) % (i8::MAX as i32 + 1))
+            .find(|id| !self.is_id_reserved(*id))
+    }
+
+}
+
+#[derive(Debug, Clone)]
+pub struct Import {
+    pub path: String,
+    pub token: Token,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PacketKind {
+    Packet,
+    Struct,
+}
+
+impl Display for PacketKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PacketKind::Packet => write!(f, "packet"),
+            PacketKind::Struct => write!(f, "struct"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Transition {
+    pub from: String,
+    pub to: String,
+    pub packet: String,
+    pub namespace: Option<String>,
+}
+
+impl Display for Transition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.from, self.to)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Clientbound,
+    Serverbound,
+}
+
+impl Direction {
+
+    pub fn from_name(name: &str) -> Option<Direction> {
+        match name {
+            "clientbound" => Some(Direction::Clientbound),
+            "serverbound" => Some(Direction::Serverbound),
+            _ => None,
+        }
+    }
+
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Clientbound => write!(f, "clientbound"),
+            Direction::Serverbound => write!(f, "serverbound"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Packet {
+    pub kind: PacketKind,
     pub id: i32,
+    pub id_reference: Option<String>,
     pub is_auto: bool,
     pub name: String,
-    pub fields: Vec<Field>
+    pub namespace: Option<String>,
+    pub parent: Option<String>,
+    pub direction: Option<Direction>,
+    pub state: Option<String>,
+    pub version: Option<u32>,
+    pub type_params: Vec<String>,
+    pub fields: Vec<Field>,
+    pub reserved_names: Vec<String>,
+    pub attributes: Vec<Attribute>,
+    pub doc: Option<String>,
+}
+
+impl Packet {
+
+    pub fn qualified_name(&self) -> String {
+        qualify_name(&self.namespace, &self.name)
+    }
+
 }
 
 #[derive(Debug, Clone)]
 pub struct Field {
     pub typ: FieldType,
-    pub name: String
+    pub name: String,
+    pub alias_type: Option<FieldType>,
+    pub default: Option<Literal>,
+    pub constraints: Vec<Constraint>,
+    pub attributes: Vec<Attribute>,
+    pub doc: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Attribute {
+    pub name: String,
+    pub args: Vec<AttributeArg>,
+}
+
+impl Attribute {
+
+    pub fn arg(&self, name: &str) -> Option<&Literal> {
+        self.args.iter()
+            .find(|arg| arg.name.as_deref() == Some(name))
+            .map(|arg| &arg.value)
+    }
+
+    pub fn positional_arg(&self, index: usize) -> Option<&Literal> {
+        self.args.iter()
+            .filter(|arg| arg.name.is_none())
+            .nth(index)
+            .map(|arg| &arg.value)
+    }
+
+}
+
+#[derive(Debug, Clone)]
+pub struct AttributeArg {
+    pub name: Option<String>,
+    pub value: Literal,
+}
+
+pub fn find_attribute<'a>(attributes: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
+    attributes.iter().find(|attribute| attribute.name == name)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstraintKind {
+    Min,
+    Max,
+    MinLen,
+    MaxLen,
+    MinItems,
+    MaxItems,
+}
+
+impl ConstraintKind {
+
+    pub fn from_name(name: &str) -> Option<ConstraintKind> {
+        match name {
+            "min" => Some(ConstraintKind::Min),
+            "max" => Some(ConstraintKind::Max),
+            "min_len" => Some(ConstraintKind::MinLen),
+            "max_len" => Some(ConstraintKind::MaxLen),
+            "min_items" => Some(ConstraintKind::MinItems),
+            "max_items" => Some(ConstraintKind::MaxItems),
+            _ => None,
+        }
+    }
+
+}
+
+impl Display for ConstraintKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstraintKind::Min => write!(f, "min"),
+            ConstraintKind::Max => write!(f, "max"),
+            ConstraintKind::MinLen => write!(f, "min_len"),
+            ConstraintKind::MaxLen => write!(f, "max_len"),
+            ConstraintKind::MinItems => write!(f, "min_items"),
+            ConstraintKind::MaxItems => write!(f, "max_items"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Constraint {
+    pub kind: ConstraintKind,
+    pub value: Literal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    Reference(String),
+}
+
+impl Display for Literal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Literal::Integer(value) => write!(f, "{}", value),
+            Literal::Float(value) => write!(f, "{:?}", value),
+            Literal::Bool(value) => write!(f, "{}", value),
+            Literal::Str(value) => write!(f, "{:?}", value),
+            Literal::Reference(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EnumKind {
+    Enum,
+    Flags,
+}
+
+impl Display for EnumKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnumKind::Enum => write!(f, "enum"),
+            EnumKind::Flags => write!(f, "flags"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Enum {
+    pub kind: EnumKind,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub typ: FieldType,
+    pub variants: Vec<EnumVariant>,
+    pub doc: Option<String>,
+}
+
+impl Enum {
+
+    pub fn qualified_name(&self) -> String {
+        qualify_name(&self.namespace, &self.name)
+    }
+
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumVariant {
+    pub name: String,
+    pub value: i32,
+    pub doc: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Constant {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub typ: FieldType,
+    pub value: Literal,
+    pub doc: Option<String>,
+}
+
+impl Constant {
+
+    pub fn qualified_name(&self) -> String {
+        qualify_name(&self.namespace, &self.name)
+    }
+
+}
+
+#[derive(Debug, Clone)]
+pub struct Rpc {
+    pub name: String,
+    pub request: String,
+    pub response: String,
+    pub doc: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Service {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub methods: Vec<Rpc>,
+    pub doc: Option<String>,
+}
+
+impl Service {
+
+    pub fn qualified_name(&self) -> String {
+        qualify_name(&self.namespace, &self.name)
+    }
+
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeAlias {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub typ: FieldType,
+    pub doc: Option<String>,
+}
+
+impl TypeAlias {
+
+    pub fn qualified_name(&self) -> String {
+        qualify_name(&self.namespace, &self.name)
+    }
+
+}
+
+#[derive(Debug, Clone)]
+pub struct Union {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub version: Option<u32>,
+    pub variants: Vec<Field>,
+    pub doc: Option<String>,
+}
+
+impl Union {
+
+    pub fn qualified_name(&self) -> String {
+        qualify_name(&self.namespace, &self.name)
+    }
+
 }
 
 pub trait AstParser {
-    fn parse(&self, tokens: &Vec<Token>) -> MorphResult<Vec<Packet>>;
+    fn parse(&self, tokens: &[Token]) -> MorphResult<Schema>;
     
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn auto_id_skips_reserved_ids() {
+
+        let hashed = hash_str_to_i8_positive("Login") as i32;
+        let mut reserved = Reserved::default();
+
+        assert_eq!(reserved.auto_id("Login"), Some(hashed));
+
+        reserved.ids.push(hashed..=hashed);
+        assert_eq!(reserved.auto_id("Login"), Some((hashed + 1) % 128));
+
+        reserved.ids.push(0..=127);
+        assert_eq!(reserved.auto_id("Login"), None);
+    }
+
+}
@@ -1,27 +1,41 @@
 use crate::core::token::KeywordKind;
 use crate::core::token::KeywordKind::{Fields, Identifier};
-use crate::core::FieldType::{Array, Nested};
+use crate::core::FieldType::{Array, FixedArray, Generic, Map, Nested, Optional, Set};
 use crate::core::TokenKind::{BraceClose, Keyword, Qualifier};
-use crate::core::{AstParser, Field, FieldType, OperatorKind, Packet, ParserError, Token, TokenKind};
+use crate::core::{ArraySize, AstParser, Attribute, AttributeArg, Constant, Constraint, ConstraintKind, Direction, Enum, EnumKind, EnumVariant, Field, FieldType, Import, Literal, OperatorKind, Packet, PacketKind, ParserError, Reserved, Rpc, Schema, Service, Transition, TypeAlias, Token, TokenKind, Union, qualify_name};
 use crate::utils::MorphResult::{Errors, Success};
 use crate::utils::{MorphError, MorphResult};
 
 pub struct SimpleParser;
 
+enum PacketId {
+    Auto,
+    Value(i32),
+    Reference(String),
+}
+
 impl SimpleParser {
 
-    fn parse_packet(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<Packet> {
+    fn parse_packet(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        kind: PacketKind,
+        doc: Option<String>,
+        attributes: Vec<Attribute>
+    ) -> MorphResult<Packet> {
 
         let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
 
         iter.next();
 
-        let id: i32;
-        let is_auto: bool;
+        let mut id: i32 = -1;
+        let mut id_reference: Option<String> = None;
+        let mut is_auto: bool = false;
         let name: String;
         let mut fields: Vec<Field> = Vec::new();
+        let mut reserved_names: Vec<String> = Vec::new();
 
-        match self.parse_packet_qualifier(iter) {
+        match self.parse_qualifier(iter, kind.to_string().as_str()) {
             Success(value) => name = value,
             Errors(errors) => {
                 name = "not found".to_string();
@@ -29,43 +43,64 @@ impl SimpleParser {
             },
         }
 
-        match self.parse_brace_open(iter) {
-            Errors(errors) => all_errors.extend(errors),
-            _ => {}
+        let mut type_params: Vec<String> = Vec::new();
+
+        if let Some(token) = iter.peek().filter(|token| token.kind == TokenKind::AngleOpen).cloned() {
+
+            if kind == PacketKind::Packet {
+                let err = ParserError {
+                    message: format!("Packet '{}' can not have type parameters, only structs can be generic", name),
+                    token: Some(token.clone())
+                };
+                all_errors.push(Box::new(err));
+            }
+
+            match self.parse_type_params(iter) {
+                Success(params) => type_params = params,
+                Errors(errors) => all_errors.extend(errors),
+            }
+
         }
 
-        match self.parse_packet_id_keyword(iter) {
-            Errors(errors) => all_errors.extend(errors),
-            _ => {}
+        let mut parent: Option<String> = None;
+
+        if iter.peek().is_some_and(|token| token.kind == Qualifier("extends".to_string())) {
+
+            iter.next();
+
+            match self.parse_qualifier(iter, "parent") {
+                Success(value) => parent = Some(value),
+                Errors(errors) => all_errors.extend(errors),
+            }
+
         }
 
-        match self.parse_operator(iter, OperatorKind::Equals) {
+        match self.parse_brace_open(iter) {
             Errors(errors) => all_errors.extend(errors),
             _ => {}
         }
 
-        if self.has_keyword(iter, KeywordKind::Auto) {
-            id = -1;
-            is_auto = true;
-            match self.parse_auto_keyword(iter) {
-                Errors(errors) => all_errors.extend(errors),
-                _ => {}
+        if kind == PacketKind::Packet || self.has_keyword(iter, Identifier) {
+
+            if kind == PacketKind::Struct {
+                let err = ParserError {
+                    message: format!("Struct '{}' can not have an id", name),
+                    token: iter.peek().map(|token| (*token).clone())
+                };
+                all_errors.push(Box::new(err));
             }
 
-        } else {
-            is_auto = false;
-            match self.parse_i32_number(iter) {
-                Success(num) => {
-                    id = num;
-                },
-                Errors(errors) => {
-                    id = -1;
-                    all_errors.extend(errors)
-                },
+            match self.parse_packet_id(iter) {
+                Success(PacketId::Auto) => is_auto = true,
+                Success(PacketId::Value(value)) => id = value,
+                Success(PacketId::Reference(name)) => id_reference = Some(name),
+                Errors(errors) => all_errors.extend(errors),
             }
 
         }
 
+        self.parse_reserved_names(iter, kind, name.as_str(), &mut reserved_names, &mut all_errors);
+
         if self.has_keyword(iter, Fields) {
 
             iter.next();
@@ -88,256 +123,1101 @@ impl SimpleParser {
             }
         }
 
+        self.parse_reserved_names(iter, kind, name.as_str(), &mut reserved_names, &mut all_errors);
+
         match self.parse_brace_close(iter) {
             Errors(errors) => all_errors.extend(errors),
             _ => {}
         }
 
         if all_errors.is_empty() {
-            Success(Packet { id, is_auto, name, fields })
+            Success(Packet { kind, id, id_reference, is_auto, name, namespace: None, parent, direction: None, state: None, version: None, type_params, fields, reserved_names, attributes, doc })
         } else {
             Errors(all_errors)
         }
 
     }
 
-    fn has_token(
-        &self, iter:
-        &mut std::iter::Peekable<std::slice::Iter<Token>>,
-        expected: TokenKind
-    ) -> bool {
-        match iter.peek() {
-            Some(token) => {
-                if (*token).kind == expected {
-                    iter.next();
-                    true
-                } else {
-                    false
-                }
-            }
-            None => false
-        }
-    }
+    fn parse_type_params(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<Vec<String>> {
 
-    fn has_keyword(
-        &self, iter:
-        &mut std::iter::Peekable<std::slice::Iter<Token>>,
-        expected: KeywordKind
-    ) -> bool {
-        match iter.peek() {
-            Some(token) => {
-                if let Keyword(keyword_kind) = &token.kind {
-                    expected == *keyword_kind
+        iter.next();
 
-                } else {
-                    false
+        let mut params: Vec<String> = Vec::new();
 
-                }
-            }
-            None => false
-        }
-    }
+        loop {
 
-    fn expect<F, R>(
-        &self,
-        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
-        f: F
-    ) -> MorphResult<R>
-    where
-        F: FnOnce(&Token) -> MorphResult<R>,
-    {
-        match iter.next() {
-            Some(token) => { f(token) }
-            None => {
-                let err = ParserError {
-                    message: "Unexpected end of tokens".to_string(),
-                    token: None
-                };
-                Errors(vec![Box::new(err)])
+            match self.parse_qualifier(iter, "type parameter") {
+                Success(param) => params.push(param),
+                Errors(errors) => return Errors(errors)
             }
-        }
-    }
 
-    fn expect_token(
-        &self,
-        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
-    ) -> MorphResult<Token>  {
-        match iter.next() {
-            Some(token) => {
-                Success(token.clone())
-            }
-            None => {
-                let err = ParserError {
-                    message: "Unexpected end of tokens".to_string(),
-                    token: None
-                };
-                Errors(vec![Box::new(err)])
+            if !self.has_token(iter, TokenKind::Comma) {
+                break;
             }
-        }
-    }
 
-    fn expect_kind(
-        &self,
-        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
-        expected: TokenKind
-    ) -> MorphResult<()> {
+        }
 
-        self.expect(iter, |token| {
+        match self.expect_kind(iter, TokenKind::AngleClose) {
+            Success(()) => Success(params),
+            Errors(errors) => Errors(errors)
+        }
 
-            if expected == token.kind {
-                Success(())
+    }
 
-            } else {
-                let err = ParserError {
-                    message: format!("Expected '{}', but got '{}'", expected, token.kind),
-                    token: Some(token.clone())
-                };
-                Errors(vec![Box::new(err)])
-            }
+    fn parse_reserved(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<Reserved> {
 
-        })
-    }
+        iter.next();
 
-    fn parse_packet_qualifier(
-        &self,
-        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>
-    ) -> MorphResult<String> {
+        let mut reserved = Reserved::default();
 
-        self.expect(iter, |token| {
-            if let Qualifier(name) = &token.kind {
-                Success(name.clone())
+        loop {
 
-            } else {
+            let Some(token) = iter.peek().cloned() else {
                 let err = ParserError {
-                    message: format!("Expected packet qualifier, but got '{}'", token.kind),
-                    token: Some(token.clone())
+                    message: "Unexpected end of tokens".to_string(),
+                    token: None
                 };
-                Errors(vec![Box::new(err)])
-            }
-        })
-
-    }
-
-    fn parse_brace_open(
-        &self, iter:
-        &mut std::iter::Peekable<std::slice::Iter<Token>>
-    ) -> MorphResult<()> {
-        self.expect_kind(iter, TokenKind::BraceOpen)
-    }
-
-    fn parse_brace_close(
-        &self, iter:
-        &mut std::iter::Peekable<std::slice::Iter<Token>>
-    ) -> MorphResult<()> {
-        self.expect_kind(iter, BraceClose)
-    }
+                return Errors(vec![Box::new(err)]);
+            };
 
-    fn parse_keyword(
-        &self, iter:
-        &mut std::iter::Peekable<std::slice::Iter<Token>>,
-        expected: KeywordKind
-    ) -> MorphResult<()> {
-
-        self.expect(iter, |token| {
             match &token.kind {
-                Keyword(keyword_kind) => {
-                    if keyword_kind.clone() == expected {
+                TokenKind::StringLiteral(name) => {
+                    iter.next();
+                    reserved.names.push(name.clone());
+                }
+                TokenKind::Number(_) => {
 
-                        Success(())
+                    let start = match self.parse_i32_number(iter) {
+                        Success(start) => start,
+                        Errors(errors) => return Errors(errors),
+                    };
 
+                    let end = if self.has_token(iter, TokenKind::Operator(OperatorKind::Range)) {
+                        match self.parse_i32_number(iter) {
+                            Success(end) => end,
+                            Errors(errors) => return Errors(errors),
+                        }
                     } else {
+                        start
+                    };
+
+                    if end < start {
                         let err = ParserError {
-                            message: format!("Expected keyword '{}', but got another keyword '{}", expected, keyword_kind),
+                            message: format!("Reserved range {}..{} is empty", start, end),
                             token: Some(token.clone())
                         };
-
-                        Errors(vec![Box::new(err)])
+                        return Errors(vec![Box::new(err)]);
                     }
+
+                    reserved.ids.push(start..=end);
                 }
                 _ => {
                     let err = ParserError {
-                        message: format!("Expected keyword '{}', but got '{}", expected, token.kind),
+                        message: format!("Expected reserved id or name, but got '{}'", token.kind),
                         token: Some(token.clone())
                     };
-
-                    Errors(vec![Box::new(err)])
+                    return Errors(vec![Box::new(err)]);
                 }
             }
-        })
 
-    }
+            if !self.has_token(iter, TokenKind::Comma) {
+                break;
+            }
+
+        }
+
+        match self.expect_kind(iter, TokenKind::Semicolon) {
+            Success(()) => Success(reserved),
+            Errors(errors) => Errors(errors)
+        }
 
-    fn parse_packet_id_keyword(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<()> {
-        self.parse_keyword(iter, Identifier)
     }
 
-    fn parse_operator(
+    fn parse_reserved_names(
         &self,
         iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
-        expected: OperatorKind
-    ) -> MorphResult<()> {
-
-        self.expect(iter, |token| {
-            if let TokenKind::Operator(kind) = &token.kind {
-                if expected == *kind {
-                    Success(())
-                } else {
-                    let err = ParserError {
-                        message: format!("Expected operator '{}', but got operator '{}'", expected, kind),
-                        token: Some(token.clone())
-                    };
-                    Errors(vec![Box::new(err)])
+        kind: PacketKind,
+        name: &str,
+        reserved_names: &mut Vec<String>,
+        all_errors: &mut Vec<Box<dyn MorphError>>
+    ) {
+
+        while let Some(token) = iter.peek().filter(|token| token.kind == Qualifier("reserved".to_string())).cloned() {
+            match self.parse_reserved(iter) {
+                Success(reserved) => {
+                    if !reserved.ids.is_empty() {
+                        let err = ParserError {
+                            message: format!("Ids can only be reserved at the top level, not in {} '{}'", kind, name),
+                            token: Some(token.clone())
+                        };
+                        all_errors.push(Box::new(err));
+                    }
+                    reserved_names.extend(reserved.names);
                 }
-
-            } else {
-                let err = ParserError {
-                    message: format!("Expected operator '{}', but got '{}'", expected, token.kind),
-                    token: Some(token.clone())
-                };
-                Errors(vec![Box::new(err)])
+                Errors(errors) => all_errors.extend(errors),
             }
-        })
+        }
 
     }
 
-    fn parse_auto_keyword(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<()> {
-        self.parse_keyword(iter, KeywordKind::Auto)
-    }
+    fn parse_type_args(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<Vec<FieldType>> {
 
-    fn parse_i32_number(
-        &self,
-        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>
-    ) -> MorphResult<i32> {
-        self.expect(iter, |token| {
-            if let TokenKind::Number(num) = &token.kind {
-                Success(*num)
-            } else {
-                let err = ParserError {
-                    message: format!("Expected i32 number, but got '{}'", token.kind),
-                    token: Some(token.clone())
-                };
-                Errors(vec![Box::new(err)])
+        let mut args: Vec<FieldType> = Vec::new();
+
+        loop {
+
+            match self.parse_type(iter) {
+                Success(arg) => args.push(arg),
+                Errors(errors) => return Errors(errors)
             }
-        })
-    }
 
-    fn parse_fields(
-        &self,
-        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>
-    ) -> MorphResult<Vec<Field>> {
+            if !self.has_token(iter, TokenKind::Comma) {
+                break;
+            }
 
-        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
-        let mut fields: Vec<Field> = Vec::new();
+        }
 
-        while let Some(token) = iter.peek() {
+        match self.expect_kind(iter, TokenKind::AngleClose) {
+            Success(()) => Success(args),
+            Errors(errors) => Errors(errors)
+        }
 
-            match &token.kind {
+    }
 
-                BraceClose => {
-                    break;
-                }
-                _ => {
-                    match self.parse_field(iter) {
+    fn parse_packet_id(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<PacketId> {
+
+        if let Errors(errors) = self.parse_packet_id_keyword(iter) {
+            return Errors(errors);
+        }
+
+        if let Errors(errors) = self.parse_operator(iter, OperatorKind::Equals) {
+            return Errors(errors);
+        }
+
+        if self.has_keyword(iter, KeywordKind::Auto) {
+            match self.parse_auto_keyword(iter) {
+                Success(()) => Success(PacketId::Auto),
+                Errors(errors) => Errors(errors),
+            }
+        } else if let Some(Token { kind: Qualifier(name), .. }) = iter.peek() {
+            iter.next();
+            Success(PacketId::Reference(name.clone()))
+        } else {
+            match self.parse_i32_number(iter) {
+                Success(num) => Success(PacketId::Value(num)),
+                Errors(errors) => Errors(errors),
+            }
+        }
+
+    }
+
+    fn parse_const(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        doc: Option<String>
+    ) -> MorphResult<Constant> {
+
+        iter.next();
+
+        let name = match self.parse_qualifier(iter, "constant") {
+            Success(name) => name,
+            Errors(errors) => return Errors(errors),
+        };
+
+        if let Errors(errors) = self.expect_kind(iter, TokenKind::Colon) {
+            return Errors(errors);
+        }
+
+        let typ = match self.parse_type(iter) {
+            Success(typ) => typ,
+            Errors(errors) => return Errors(errors),
+        };
+
+        if let Errors(errors) = self.parse_operator(iter, OperatorKind::Equals) {
+            return Errors(errors);
+        }
+
+        let value = match self.parse_literal(iter) {
+            Success(value) => value,
+            Errors(errors) => return Errors(errors),
+        };
+
+        match self.expect_kind(iter, TokenKind::Semicolon) {
+            Success(()) => Success(Constant { name, namespace: None, typ, value, doc }),
+            Errors(errors) => Errors(errors),
+        }
+
+    }
+
+    fn parse_type_alias(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        doc: Option<String>
+    ) -> MorphResult<TypeAlias> {
+
+        iter.next();
+
+        let name = match self.parse_qualifier(iter, "type alias") {
+            Success(name) => name,
+            Errors(errors) => return Errors(errors),
+        };
+
+        if let Errors(errors) = self.parse_operator(iter, OperatorKind::Equals) {
+            return Errors(errors);
+        }
+
+        let typ = match self.parse_type(iter) {
+            Success(typ) => typ,
+            Errors(errors) => return Errors(errors),
+        };
+
+        match self.expect_kind(iter, TokenKind::Semicolon) {
+            Success(()) => Success(TypeAlias { name, namespace: None, typ, doc }),
+            Errors(errors) => Errors(errors),
+        }
+
+    }
+
+    fn parse_import(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<Import> {
+
+        iter.next();
+
+        let import = match self.expect_token(iter) {
+            Success(token) => {
+                if let TokenKind::StringLiteral(path) = &token.kind {
+                    Import { path: path.clone(), token: token.clone() }
+                } else {
+                    let err = ParserError {
+                        message: format!("Expected import path, but got '{}'", token.kind),
+                        token: Some(token.clone())
+                    };
+                    return Errors(vec![Box::new(err)]);
+                }
+            }
+            Errors(errors) => return Errors(errors),
+        };
+
+        match self.expect_kind(iter, TokenKind::Semicolon) {
+            Success(()) => Success(import),
+            Errors(errors) => Errors(errors),
+        }
+
+    }
+
+    fn parse_namespace(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<String> {
+
+        iter.next();
+
+        let token = match self.expect_token(iter) {
+            Success(token) => token,
+            Errors(errors) => return Errors(errors),
+        };
+
+        let namespace = match &token.kind {
+            Qualifier(namespace) if self.is_valid_namespace(namespace) => namespace.clone(),
+            _ => {
+                let err = ParserError {
+                    message: format!("Expected namespace, but got '{}'", token.kind),
+                    token: Some(token.clone())
+                };
+                return Errors(vec![Box::new(err)]);
+            }
+        };
+
+        match self.expect_kind(iter, TokenKind::Semicolon) {
+            Success(()) => Success(namespace),
+            Errors(errors) => Errors(errors),
+        }
+
+    }
+
+    fn parse_version(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<u32> {
+
+        iter.next();
+
+        if let Errors(errors) = self.parse_operator(iter, OperatorKind::Equals) {
+            return Errors(errors);
+        }
+
+        let version = match self.expect(iter, |token| {
+            match &token.kind {
+                TokenKind::Number(num) => match u32::try_from(*num) {
+                    Ok(version) => Success(version),
+                    Err(_) => {
+                        let err = ParserError {
+                            message: format!("Version {} must be a non-negative number that fits in u32", num),
+                            token: Some(token.clone())
+                        };
+                        Errors(vec![Box::new(err)])
+                    }
+                },
+                _ => {
+                    let err = ParserError {
+                        message: format!("Expected version number, but got '{}'", token.kind),
+                        token: Some(token.clone())
+                    };
+                    Errors(vec![Box::new(err)])
+                }
+            }
+        }) {
+            Success(version) => version,
+            Errors(errors) => return Errors(errors),
+        };
+
+        match self.expect_kind(iter, TokenKind::Semicolon) {
+            Success(()) => Success(version),
+            Errors(errors) => Errors(errors),
+        }
+
+    }
+
+    fn is_valid_namespace(&self, namespace: &str) -> bool {
+        namespace.split('.').all(|segment| {
+            let mut chars = segment.chars();
+            match chars.next() {
+                Some(first) => {
+                    (first.is_ascii_alphabetic() || first == '_')
+                        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+                }
+                None => false,
+            }
+        })
+    }
+
+    fn parse_enum(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        kind: EnumKind,
+        doc: Option<String>
+    ) -> MorphResult<Enum> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        iter.next();
+
+        let name: String;
+        let mut typ: FieldType = FieldType::U8;
+        let mut variants: Vec<EnumVariant> = Vec::new();
+
+        match self.parse_qualifier(iter, kind.to_string().as_str()) {
+            Success(value) => name = value,
+            Errors(errors) => {
+                name = "not found".to_string();
+                all_errors.extend(errors)
+            },
+        }
+
+        if let Errors(errors) = self.expect_kind(iter, TokenKind::Colon) {
+            all_errors.extend(errors);
+        }
+
+        match self.parse_type(iter) {
+            Success(value) => typ = value,
+            Errors(errors) => all_errors.extend(errors),
+        }
+
+        if let Errors(errors) = self.parse_brace_open(iter) {
+            all_errors.extend(errors);
+        }
+
+        let mut next_value: i32 = 0;
+
+        while !self.has_token(iter, BraceClose) {
+
+            match self.parse_enum_variant(iter, next_value) {
+                Success(variant) => {
+                    next_value = variant.value.wrapping_add(1);
+                    variants.push(variant);
+                }
+                Errors(errors) => {
+                    all_errors.extend(errors);
+                    break;
+                }
+            }
+
+            if !self.has_token(iter, TokenKind::Comma) {
+                if let Errors(errors) = self.parse_brace_close(iter) {
+                    all_errors.extend(errors);
+                }
+                break;
+            }
+
+        }
+
+        if all_errors.is_empty() {
+            Success(Enum { kind, name, namespace: None, typ, variants, doc })
+        } else {
+            Errors(all_errors)
+        }
+
+    }
+
+    fn parse_union(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        doc: Option<String>
+    ) -> MorphResult<Union> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        iter.next();
+
+        let name: String;
+        let mut variants: Vec<Field> = Vec::new();
+
+        match self.parse_qualifier(iter, "union") {
+            Success(value) => name = value,
+            Errors(errors) => {
+                name = "not found".to_string();
+                all_errors.extend(errors)
+            },
+        }
+
+        if let Errors(errors) = self.parse_brace_open(iter) {
+            all_errors.extend(errors);
+        }
+
+        match self.parse_fields(iter) {
+            Success(parsed_variants) => variants.extend(parsed_variants),
+            Errors(errors) => all_errors.extend(errors),
+        }
+
+        if let Errors(errors) = self.parse_brace_close(iter) {
+            all_errors.extend(errors);
+        }
+
+        if all_errors.is_empty() {
+            Success(Union { name, namespace: None, version: None, variants, doc })
+        } else {
+            Errors(all_errors)
+        }
+
+    }
+
+    fn parse_directed_packet(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        direction: Direction,
+        doc: Option<String>,
+        attributes: Vec<Attribute>
+    ) -> MorphResult<Packet> {
+
+        if self.has_keyword(iter, KeywordKind::Struct) {
+            return self.parse_misplaced_struct(iter, "direction", doc, attributes);
+        }
+
+        if !self.has_keyword(iter, KeywordKind::Packet) {
+            let err = ParserError {
+                message: format!("Expected packet after direction '{}'", direction),
+                token: iter.peek().map(|token| (*token).clone())
+            };
+            return Errors(vec![Box::new(err)]);
+        }
+
+        match self.parse_packet(iter, PacketKind::Packet, doc, attributes) {
+            Success(packet) => Success(Packet { direction: Some(direction), ..packet }),
+            Errors(errors) => Errors(errors)
+        }
+
+    }
+
+    fn parse_misplaced_struct(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        scope: &str,
+        doc: Option<String>,
+        attributes: Vec<Attribute>
+    ) -> MorphResult<Packet> {
+
+        let token = iter.peek().map(|token| (*token).clone());
+
+        match self.parse_packet(iter, PacketKind::Struct, doc, attributes) {
+            Success(packet) => {
+                let err = ParserError {
+                    message: format!("Struct '{}' can not have a {}", packet.name, scope),
+                    token
+                };
+                Errors(vec![Box::new(err)])
+            }
+            Errors(errors) => Errors(errors)
+        }
+
+    }
+
+    fn parse_direction_block(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<Vec<Packet>> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+        let mut packets: Vec<Packet> = Vec::new();
+
+        iter.next();
+
+        let token = iter.peek().map(|token| (*token).clone());
+
+        let direction = match self.parse_qualifier(iter, "direction") {
+            Success(name) => match Direction::from_name(name.as_str()) {
+                Some(direction) => direction,
+                None => {
+                    let err = ParserError {
+                        message: format!("Unknown direction '{}', expected 'clientbound' or 'serverbound'", name),
+                        token
+                    };
+                    return Errors(vec![Box::new(err)]);
+                }
+            },
+            Errors(errors) => return Errors(errors),
+        };
+
+        if let Errors(errors) = self.parse_brace_open(iter) {
+            return Errors(errors);
+        }
+
+        while !self.has_token(iter, BraceClose) {
+
+            let doc = self.parse_doc(iter);
+
+            let attributes = match self.parse_attributes(iter) {
+                Success(attributes) => attributes,
+                Errors(errors) => {
+                    all_errors.extend(errors);
+                    continue;
+                }
+            };
+
+            let Some(token) = iter.peek() else {
+                let err = ParserError {
+                    message: format!("Direction block '{}' is not closed", direction),
+                    token: None
+                };
+                all_errors.push(Box::new(err));
+                break;
+            };
+
+            if !matches!(token.kind, Keyword(KeywordKind::Packet) | Keyword(KeywordKind::Struct)) {
+                let err = ParserError {
+                    message: format!("Expected packet in direction block, but got '{}'", token.kind),
+                    token: Some((*token).clone())
+                };
+                all_errors.push(Box::new(err));
+                iter.next();
+                continue;
+            }
+
+            match self.parse_directed_packet(iter, direction, doc, attributes) {
+                Success(packet) => packets.push(packet),
+                Errors(errors) => all_errors.extend(errors)
+            }
+
+        }
+
+        if all_errors.is_empty() {
+            Success(packets)
+        } else {
+            Errors(all_errors)
+        }
+
+    }
+
+    fn parse_state_block(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<(String, Vec<Packet>)> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+        let mut packets: Vec<Packet> = Vec::new();
+
+        iter.next();
+
+        let state = match self.parse_qualifier(iter, "state") {
+            Success(state) => state,
+            Errors(errors) => return Errors(errors),
+        };
+
+        if let Errors(errors) = self.parse_brace_open(iter) {
+            return Errors(errors);
+        }
+
+        while !self.has_token(iter, BraceClose) {
+
+            let doc = self.parse_doc(iter);
+
+            let attributes = match self.parse_attributes(iter) {
+                Success(attributes) => attributes,
+                Errors(errors) => {
+                    all_errors.extend(errors);
+                    continue;
+                }
+            };
+
+            let Some(token) = iter.peek() else {
+                let err = ParserError {
+                    message: format!("State block '{}' is not closed", state),
+                    token: None
+                };
+                all_errors.push(Box::new(err));
+                break;
+            };
+
+            match &token.kind {
+                Keyword(KeywordKind::Packet) => {
+                    match self.parse_packet(iter, PacketKind::Packet, doc, attributes) {
+                        Success(packet) => packets.push(packet),
+                        Errors(errors) => all_errors.extend(errors)
+                    }
+                }
+                Keyword(KeywordKind::Struct) => {
+                    if let Errors(errors) = self.parse_misplaced_struct(iter, "state", doc, attributes) {
+                        all_errors.extend(errors);
+                    }
+                }
+                Qualifier(qualifier) if qualifier == "direction" => {
+                    match self.parse_direction_block(iter) {
+                        Success(parsed) => packets.extend(parsed),
+                        Errors(errors) => all_errors.extend(errors)
+                    }
+                }
+                Qualifier(qualifier) if Direction::from_name(qualifier).is_some() => {
+                    let direction = Direction::from_name(qualifier);
+                    iter.next();
+                    if let Some(direction) = direction {
+                        match self.parse_directed_packet(iter, direction, doc, attributes) {
+                            Success(packet) => packets.push(packet),
+                            Errors(errors) => all_errors.extend(errors)
+                        }
+                    }
+                }
+                _ => {
+                    let err = ParserError {
+                        message: format!("Expected packet in state block, but got '{}'", token.kind),
+                        token: Some((*token).clone())
+                    };
+                    all_errors.push(Box::new(err));
+                    iter.next();
+                }
+            }
+
+        }
+
+        for packet in &mut packets {
+            packet.state = Some(state.clone());
+        }
+
+        if all_errors.is_empty() {
+            Success((state, packets))
+        } else {
+            Errors(all_errors)
+        }
+
+    }
+
+    fn parse_transition(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<Transition> {
+
+        iter.next();
+
+        let from = match self.parse_qualifier(iter, "state") {
+            Success(state) => state,
+            Errors(errors) => return Errors(errors),
+        };
+
+        if let Errors(errors) = self.expect_kind(iter, TokenKind::Operator(OperatorKind::Arrow)) {
+            return Errors(errors);
+        }
+
+        let to = match self.parse_qualifier(iter, "state") {
+            Success(state) => state,
+            Errors(errors) => return Errors(errors),
+        };
+
+        if let Errors(errors) = self.expect_kind(iter, Qualifier("on".to_string())) {
+            return Errors(errors);
+        }
+
+        let packet = match self.parse_qualifier(iter, "packet") {
+            Success(packet) => packet,
+            Errors(errors) => return Errors(errors),
+        };
+
+        match self.expect_kind(iter, TokenKind::Semicolon) {
+            Success(()) => Success(Transition { from, to, packet, namespace: None }),
+            Errors(errors) => Errors(errors),
+        }
+
+    }
+
+    fn parse_service(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        doc: Option<String>
+    ) -> MorphResult<Service> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+        let mut methods: Vec<Rpc> = Vec::new();
+
+        iter.next();
+
+        let name = match self.parse_qualifier(iter, "service") {
+            Success(name) => name,
+            Errors(errors) => return Errors(errors),
+        };
+
+        if let Errors(errors) = self.parse_brace_open(iter) {
+            return Errors(errors);
+        }
+
+        while !self.has_token(iter, BraceClose) {
+
+            let doc = self.parse_doc(iter);
+
+            match self.parse_rpc(iter, doc) {
+                Success(rpc) => methods.push(rpc),
+                Errors(errors) => {
+                    all_errors.extend(errors);
+                    break;
+                }
+            }
+
+        }
+
+        if all_errors.is_empty() {
+            Success(Service { name, namespace: None, methods, doc })
+        } else {
+            Errors(all_errors)
+        }
+
+    }
+
+    fn parse_rpc(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        doc: Option<String>
+    ) -> MorphResult<Rpc> {
+
+        if let Errors(errors) = self.expect_kind(iter, Qualifier("rpc".to_string())) {
+            return Errors(errors);
+        }
+
+        let name = match self.parse_qualifier(iter, "rpc") {
+            Success(name) => name,
+            Errors(errors) => return Errors(errors),
+        };
+
+        if let Errors(errors) = self.expect_kind(iter, TokenKind::ParenOpen) {
+            return Errors(errors);
+        }
+
+        let request = match self.parse_qualifier(iter, "request") {
+            Success(request) => request,
+            Errors(errors) => return Errors(errors),
+        };
+
+        if let Errors(errors) = self.expect_kind(iter, TokenKind::ParenClose) {
+            return Errors(errors);
+        }
+
+        if let Errors(errors) = self.expect_kind(iter, Qualifier("returns".to_string())) {
+            return Errors(errors);
+        }
+
+        let response = match self.parse_qualifier(iter, "response") {
+            Success(response) => response,
+            Errors(errors) => return Errors(errors),
+        };
+
+        match self.expect_kind(iter, TokenKind::Semicolon) {
+            Success(()) => Success(Rpc { name, request, response, doc }),
+            Errors(errors) => Errors(errors),
+        }
+
+    }
+
+    fn parse_enum_variant(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        implicit_value: i32
+    ) -> MorphResult<EnumVariant> {
+
+        let doc = self.parse_doc(iter);
+
+        let name = match self.parse_qualifier(iter, "enum variant") {
+            Success(name) => name,
+            Errors(errors) => return Errors(errors),
+        };
+
+        let value = if self.has_token(iter, TokenKind::Operator(OperatorKind::Equals)) {
+            match self.parse_i32_number(iter) {
+                Success(num) => num,
+                Errors(errors) => return Errors(errors),
+            }
+        } else {
+            implicit_value
+        };
+
+        Success(EnumVariant { name, value, doc })
+    }
+
+    fn parse_doc(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> Option<String> {
+
+        let mut lines: Vec<String> = Vec::new();
+
+        while let Some(token) = iter.peek() {
+            if let TokenKind::DocComment(text) = &token.kind {
+                lines.push(text.clone());
+                iter.next();
+            } else {
+                break;
+            }
+        }
+
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        }
+    }
+
+    fn has_token(
+        &self, iter:
+        &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        expected: TokenKind
+    ) -> bool {
+        match iter.peek() {
+            Some(token) => {
+                if (*token).kind == expected {
+                    iter.next();
+                    true
+                } else {
+                    false
+                }
+            }
+            None => false
+        }
+    }
+
+    fn has_keyword(
+        &self, iter:
+        &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        expected: KeywordKind
+    ) -> bool {
+        match iter.peek() {
+            Some(token) => {
+                if let Keyword(keyword_kind) = &token.kind {
+                    expected == *keyword_kind
+
+                } else {
+                    false
+
+                }
+            }
+            None => false
+        }
+    }
+
+    fn expect<F, R>(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        f: F
+    ) -> MorphResult<R>
+    where
+        F: FnOnce(&Token) -> MorphResult<R>,
+    {
+        match iter.next() {
+            Some(token) => { f(token) }
+            None => {
+                let err = ParserError {
+                    message: "Unexpected end of tokens".to_string(),
+                    token: None
+                };
+                Errors(vec![Box::new(err)])
+            }
+        }
+    }
+
+    fn expect_token(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+    ) -> MorphResult<Token>  {
+        match iter.next() {
+            Some(token) => {
+                Success(token.clone())
+            }
+            None => {
+                let err = ParserError {
+                    message: "Unexpected end of tokens".to_string(),
+                    token: None
+                };
+                Errors(vec![Box::new(err)])
+            }
+        }
+    }
+
+    fn expect_kind(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        expected: TokenKind
+    ) -> MorphResult<()> {
+
+        self.expect(iter, |token| {
+
+            if expected == token.kind {
+                Success(())
+
+            } else {
+                let err = ParserError {
+                    message: format!("Expected '{}', but got '{}'", expected, token.kind),
+                    token: Some(token.clone())
+                };
+                Errors(vec![Box::new(err)])
+            }
+
+        })
+    }
+
+    fn parse_qualifier(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        description: &str
+    ) -> MorphResult<String> {
+
+        self.expect(iter, |token| {
+            if let Qualifier(name) = &token.kind {
+                Success(name.clone())
+
+            } else {
+                let err = ParserError {
+                    message: format!("Expected {} qualifier, but got '{}'", description, token.kind),
+                    token: Some(token.clone())
+                };
+                Errors(vec![Box::new(err)])
+            }
+        })
+
+    }
+
+    fn parse_brace_open(
+        &self, iter:
+        &mut std::iter::Peekable<std::slice::Iter<Token>>
+    ) -> MorphResult<()> {
+        self.expect_kind(iter, TokenKind::BraceOpen)
+    }
+
+    fn parse_brace_close(
+        &self, iter:
+        &mut std::iter::Peekable<std::slice::Iter<Token>>
+    ) -> MorphResult<()> {
+        self.expect_kind(iter, BraceClose)
+    }
+
+    fn parse_keyword(
+        &self, iter:
+        &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        expected: KeywordKind
+    ) -> MorphResult<()> {
+
+        self.expect(iter, |token| {
+            match &token.kind {
+                Keyword(keyword_kind) => {
+                    if keyword_kind.clone() == expected {
+
+                        Success(())
+
+                    } else {
+                        let err = ParserError {
+                            message: format!("Expected keyword '{}', but got another keyword '{}", expected, keyword_kind),
+                            token: Some(token.clone())
+                        };
+
+                        Errors(vec![Box::new(err)])
+                    }
+                }
+                _ => {
+                    let err = ParserError {
+                        message: format!("Expected keyword '{}', but got '{}", expected, token.kind),
+                        token: Some(token.clone())
+                    };
+
+                    Errors(vec![Box::new(err)])
+                }
+            }
+        })
+
+    }
+
+    fn parse_packet_id_keyword(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<()> {
+        self.parse_keyword(iter, Identifier)
+    }
+
+    fn parse_operator(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        expected: OperatorKind
+    ) -> MorphResult<()> {
+
+        self.expect(iter, |token| {
+            if let TokenKind::Operator(kind) = &token.kind {
+                if expected == *kind {
+                    Success(())
+                } else {
+                    let err = ParserError {
+                        message: format!("Expected operator '{}', but got operator '{}'", expected, kind),
+                        token: Some(token.clone())
+                    };
+                    Errors(vec![Box::new(err)])
+                }
+
+            } else {
+                let err = ParserError {
+                    message: format!("Expected operator '{}', but got '{}'", expected, token.kind),
+                    token: Some(token.clone())
+                };
+                Errors(vec![Box::new(err)])
+            }
+        })
+
+    }
+
+    fn parse_auto_keyword(&self, iter: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> MorphResult<()> {
+        self.parse_keyword(iter, KeywordKind::Auto)
+    }
+
+    fn parse_i32_number(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>
+    ) -> MorphResult<i32> {
+        self.expect(iter, |token| {
+            if let TokenKind::Number(num) = &token.kind {
+                match i32::try_from(*num) {
+                    Ok(num) => Success(num),
+                    Err(_) => {
+                        let err = ParserError {
+                            message: format!("Number {} does not fit in i32", num),
+                            token: Some(token.clone())
+                        };
+                        Errors(vec![Box::new(err)])
+                    }
+                }
+            } else {
+                let err = ParserError {
+                    message: format!("Expected i32 number, but got '{}'", token.kind),
+                    token: Some(token.clone())
+                };
+                Errors(vec![Box::new(err)])
+            }
+        })
+    }
+
+    fn parse_fields(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>
+    ) -> MorphResult<Vec<Field>> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+        let mut fields: Vec<Field> = Vec::new();
+
+        while let Some(token) = iter.peek() {
+
+            match &token.kind {
+
+                BraceClose => {
+                    break;
+                }
+                _ => {
+                    match self.parse_field(iter) {
                         Success(field) => fields.push(field),
                         Errors(errors) => all_errors.extend(errors),
                     }
@@ -346,51 +1226,274 @@ impl SimpleParser {
 
         }
 
-        if all_errors.is_empty() {
-            Success(fields)
+        if all_errors.is_empty() {
+            Success(fields)
+
+        } else {
+            Errors(all_errors)
+
+        }
+    }
+
+    fn parse_field(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>
+    ) -> MorphResult<Field> {
+
+        let doc = self.parse_doc(iter);
+
+        let attributes = match self.parse_attributes(iter) {
+            Success(attributes) => attributes,
+            Errors(errors) => return Errors(errors)
+        };
+
+        let mut constraints: Vec<Constraint> = Vec::new();
+
+        let field_type = match self.parse_constrained_type(iter, Some(&mut constraints)) {
+            Success(typ) => typ,
+            Errors(errors) => return Errors(errors)
+        };
+
+        let token = match self.expect_token(iter) {
+            Success(token) => token,
+            Errors(errors) => return Errors(errors)
+        };
+
+        match &token.kind {
+            Qualifier(name) => {
+
+                let default = if self.has_token(iter, TokenKind::Operator(OperatorKind::Equals)) {
+                    match self.parse_literal(iter) {
+                        Success(literal) => Some(literal),
+                        Errors(errors) => return Errors(errors)
+                    }
+                } else {
+                    None
+                };
+
+                Success(Field {
+                    name: name.clone(),
+                    typ: field_type,
+                    alias_type: None,
+                    default,
+                    constraints,
+                    attributes,
+                    doc,
+                })
+            }
+            _ => {
+                let err = ParserError {
+                    message: format!("Expected field name, but got '{}'", token.kind),
+                    token: Some(token.clone())
+                };
+                Errors(vec![Box::new(err)])
+            }
+        }
+    }
+
+    fn parse_attributes(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>
+    ) -> MorphResult<Vec<Attribute>> {
+
+        let mut attributes: Vec<Attribute> = Vec::new();
+
+        while self.has_token(iter, TokenKind::At) {
+
+            let name = match self.parse_qualifier(iter, "attribute") {
+                Success(name) => name,
+                Errors(errors) => return Errors(errors)
+            };
+
+            let mut args: Vec<AttributeArg> = Vec::new();
+
+            if self.has_token(iter, TokenKind::ParenOpen) && !self.has_token(iter, TokenKind::ParenClose) {
+
+                loop {
+
+                    match self.parse_attribute_arg(iter) {
+                        Success(arg) => args.push(arg),
+                        Errors(errors) => return Errors(errors)
+                    }
+
+                    if !self.has_token(iter, TokenKind::Comma) {
+                        break;
+                    }
+
+                }
+
+                if let Errors(errors) = self.expect_kind(iter, TokenKind::ParenClose) {
+                    return Errors(errors);
+                }
+
+            }
+
+            attributes.push(Attribute { name, args });
+
+        }
+
+        Success(attributes)
+
+    }
 
+    fn parse_attribute_arg(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>
+    ) -> MorphResult<AttributeArg> {
+
+        let mut lookahead = iter.clone();
+        let is_named = matches!(lookahead.next().map(|token| &token.kind), Some(Qualifier(_)))
+            && matches!(lookahead.next().map(|token| &token.kind), Some(TokenKind::Operator(OperatorKind::Equals)));
+
+        let name = if is_named {
+            let name = match self.parse_qualifier(iter, "argument") {
+                Success(name) => name,
+                Errors(errors) => return Errors(errors)
+            };
+            iter.next();
+            Some(name)
         } else {
-            Errors(all_errors)
+            None
+        };
 
+        match self.parse_literal(iter) {
+            Success(value) => Success(AttributeArg { name, value }),
+            Errors(errors) => Errors(errors)
         }
+
     }
 
-    fn parse_field(
+    fn parse_literal(
         &self,
         iter: &mut std::iter::Peekable<std::slice::Iter<Token>>
-    ) -> MorphResult<Field> {
+    ) -> MorphResult<Literal> {
+        self.expect(iter, |token| {
+            match &token.kind {
+                TokenKind::Number(value) => Success(Literal::Integer(*value)),
+                TokenKind::FloatLiteral(value) => Success(Literal::Float(*value)),
+                TokenKind::BoolLiteral(value) => Success(Literal::Bool(*value)),
+                TokenKind::StringLiteral(value) => Success(Literal::Str(value.clone())),
+                Qualifier(name) => Success(Literal::Reference(name.clone())),
+                _ => {
+                    let err = ParserError {
+                        message: format!("Expected literal value, but got '{}'", token.kind),
+                        token: Some(token.clone())
+                    };
+                    Errors(vec![Box::new(err)])
+                }
+            }
+        })
+    }
 
-        let field_type = match self.parse_type(iter) {
-            Success(typ) => typ,
-            Errors(errors) => return Errors(errors)
+    fn parse_array_size(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>
+    ) -> MorphResult<ArraySize> {
+
+        let size = self.expect(iter, |token| {
+            match &token.kind {
+                TokenKind::Number(value) if *value > 0 && u32::try_from(*value).is_ok() => Success(ArraySize::Value(*value as u32)),
+                Qualifier(name) => Success(ArraySize::Reference(name.clone())),
+                _ => {
+                    let err = ParserError {
+                        message: format!("Expected positive array size, but got '{}'", token.kind),
+                        token: Some(token.clone())
+                    };
+                    Errors(vec![Box::new(err)])
+                }
+            }
+        });
+
+        match size {
+            Success(size) => match self.expect_kind(iter, TokenKind::BracketClose) {
+                Success(()) => Success(size),
+                Errors(errors) => Errors(errors),
+            },
+            Errors(errors) => Errors(errors),
+        }
+
+    }
+
+    fn parse_constraints(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        constraints: Option<&mut Vec<Constraint>>
+    ) -> MorphResult<()> {
+
+        let Some(open_token) = iter.peek().filter(|token| token.kind == TokenKind::ParenOpen).cloned() else {
+            return Success(());
         };
 
-        let token = match self.expect_token(iter) {
-            Success(token) => token,
-            Errors(errors) => return Errors(errors)
+        let Some(constraints) = constraints else {
+            let err = ParserError {
+                message: "Constraints are only allowed on the outer type of a field".to_string(),
+                token: Some(open_token.clone())
+            };
+            return Errors(vec![Box::new(err)]);
         };
 
-        match &token.kind {
-            Qualifier(name) => {
-                Success(Field {
-                    name: name.clone(),
-                    typ: field_type,
-                })
+        iter.next();
+
+        loop {
+
+            let kind = match self.expect(iter, |token| {
+                match &token.kind {
+                    Qualifier(name) => match ConstraintKind::from_name(name) {
+                        Some(kind) => Success(kind),
+                        None => {
+                            let err = ParserError {
+                                message: format!("Unknown constraint '{}'", name),
+                                token: Some(token.clone())
+                            };
+                            Errors(vec![Box::new(err)])
+                        }
+                    },
+                    _ => {
+                        let err = ParserError {
+                            message: format!("Expected constraint name, but got '{}'", token.kind),
+                            token: Some(token.clone())
+                        };
+                        Errors(vec![Box::new(err)])
+                    }
+                }
+            }) {
+                Success(kind) => kind,
+                Errors(errors) => return Errors(errors)
+            };
+
+            if let Errors(errors) = self.parse_operator(iter, OperatorKind::Equals) {
+                return Errors(errors);
             }
-            _ => {
-                let err = ParserError {
-                    message: format!("Expected field name, but got '{}'", token.kind),
-                    token: Some(token.clone())
-                };
-                Errors(vec![Box::new(err)])
+
+            let value = match self.parse_literal(iter) {
+                Success(value) => value,
+                Errors(errors) => return Errors(errors)
+            };
+
+            constraints.push(Constraint { kind, value });
+
+            if !self.has_token(iter, TokenKind::Comma) {
+                break;
             }
+
         }
+
+        self.expect_kind(iter, TokenKind::ParenClose)
+
     }
 
     fn parse_type(
         &self,
         iter: &mut std::iter::Peekable<std::slice::Iter<Token>>
     ) -> MorphResult<FieldType> {
+        self.parse_constrained_type(iter, None)
+    }
+
+    fn parse_constrained_type(
+        &self,
+        iter: &mut std::iter::Peekable<std::slice::Iter<Token>>,
+        constraints: Option<&mut Vec<Constraint>>
+    ) -> MorphResult<FieldType> {
 
         let token = match self.expect_token(iter) {
             Success(token) => token,
@@ -400,19 +1503,99 @@ impl SimpleParser {
         match &token.kind {
             Keyword(KeywordKind::Array) => {
 
+                let size = if self.has_token(iter, TokenKind::BracketOpen) {
+                    match self.parse_array_size(iter) {
+                        Success(size) => Some(size),
+                        Errors(errors) => return Errors(errors)
+                    }
+                } else {
+                    None
+                };
+
+                if let Errors(errors) = self.parse_constraints(iter, constraints) {
+                    return Errors(errors);
+                }
+
+                match self.parse_type(iter) {
+                    Success(inner_type) => match size {
+                        Some(size) => Success(FixedArray(Box::new(inner_type), size)),
+                        None => Success(Array(Box::new(inner_type))),
+                    },
+                    Errors(errors) => Errors(errors)
+                }
+
+            }
+
+            Qualifier(qualifier) if qualifier == "set" => {
+
+                if let Errors(errors) = self.parse_constraints(iter, constraints) {
+                    return Errors(errors);
+                }
+
+                match self.parse_type(iter) {
+                    Success(element_type) => Success(Set(Box::new(element_type))),
+                    Errors(errors) => Errors(errors)
+                }
+
+            }
+
+            Qualifier(qualifier) if qualifier == "optional" => {
+
+                match self.parse_constrained_type(iter, constraints) {
+                    Success(inner_type) => Success(Optional(Box::new(inner_type))),
+                    Errors(errors) => Errors(errors)
+                }
+
+            }
+
+            Qualifier(qualifier) if qualifier == "map" => {
+
+                if let Errors(errors) = self.parse_constraints(iter, constraints) {
+                    return Errors(errors);
+                }
+
+                let key_type = match self.parse_type(iter) {
+                    Success(key_type) => key_type,
+                    Errors(errors) => return Errors(errors)
+                };
+
                 match self.parse_type(iter) {
-                    Success(inner_type) => Success(Array(Box::new(inner_type))),
+                    Success(value_type) => Success(Map(Box::new(key_type), Box::new(value_type))),
                     Errors(errors) => Errors(errors)
                 }
 
             }
 
             Keyword(KeywordKind::FieldDefinition(field_type)) => {
-                Success(field_type.clone())
+                match self.parse_constraints(iter, constraints) {
+                    Success(()) => Success(field_type.clone()),
+                    Errors(errors) => Errors(errors)
+                }
+            }
+
+            Qualifier(qualifier) if qualifier == "bytes" => {
+                match self.parse_constraints(iter, constraints) {
+                    Success(()) => Success(FieldType::Bytes),
+                    Errors(errors) => Errors(errors)
+                }
             }
 
             Qualifier(name) => {
-                Success(Nested(name.clone()))
+
+                let field_type = if self.has_token(iter, TokenKind::AngleOpen) {
+                    match self.parse_type_args(iter) {
+                        Success(args) => Generic(name.clone(), args),
+                        Errors(errors) => return Errors(errors)
+                    }
+                } else {
+                    Nested(name.clone())
+                };
+
+                match self.parse_constraints(iter, constraints) {
+                    Success(()) => Success(field_type),
+                    Errors(errors) => Errors(errors)
+                }
+
             }
 
             _ => {
@@ -429,28 +1612,127 @@ impl SimpleParser {
 
 impl AstParser for SimpleParser {
 
-    fn parse(&self, tokens: &Vec<Token>) -> MorphResult<Vec<Packet>> {
+    fn parse(&self, tokens: &[Token]) -> MorphResult<Schema> {
 
-        let mut packets: Vec<Packet> = Vec::new();
+        let mut schema = Schema::default();
         let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
 
         let mut iter = tokens.iter().peekable();
 
-        while let Some(token) = iter.peek() {
+        loop {
+
+            let doc = self.parse_doc(&mut iter);
+
+            let attributes = match self.parse_attributes(&mut iter) {
+                Success(attributes) => attributes,
+                Errors(errors) => {
+                    all_errors.extend(errors);
+                    continue;
+                }
+            };
+
+            let Some(token) = iter.peek() else {
+                if doc.is_some() {
+                    let err = ParserError {
+                        message: "Doc comment is not followed by a declaration".to_string(),
+                        token: tokens.last().cloned()
+                    };
+                    all_errors.push(Box::new(err));
+                }
+                if !attributes.is_empty() {
+                    let err = ParserError {
+                        message: "Attribute is not followed by a declaration".to_string(),
+                        token: tokens.last().cloned()
+                    };
+                    all_errors.push(Box::new(err));
+                }
+                break;
+            };
+
+            let is_direction = matches!(&token.kind, Qualifier(qualifier) if Direction::from_name(qualifier).is_some());
+
+            if !attributes.is_empty() && !is_direction && !matches!(token.kind, Keyword(KeywordKind::Packet) | Keyword(KeywordKind::Struct)) {
+                let err = ParserError {
+                    message: format!("Attributes are only allowed on packets, structs and fields, but got '{}'", token.kind),
+                    token: Some((*token).clone())
+                };
+                all_errors.push(Box::new(err));
+            }
+
             match &token.kind {
                 Keyword(keyword_kind) => {
                     match keyword_kind {
 
                         KeywordKind::Packet => {
-                            match self.parse_packet(&mut iter) {
-                                Success(packet) => packets.push(packet),
+                            match self.parse_packet(&mut iter, PacketKind::Packet, doc, attributes) {
+                                Success(packet) => schema.packets.push(packet),
+                                Errors(errors) => all_errors.extend(errors)
+                            }
+                        }
+
+                        KeywordKind::Struct => {
+                            match self.parse_packet(&mut iter, PacketKind::Struct, doc, attributes) {
+                                Success(packet) if !packet.type_params.is_empty() => schema.templates.push(packet),
+                                Success(packet) => schema.packets.push(packet),
+                                Errors(errors) => all_errors.extend(errors)
+                            }
+                        }
+
+                        KeywordKind::Union => {
+                            match self.parse_union(&mut iter, doc) {
+                                Success(union) => schema.unions.push(union),
+                                Errors(errors) => all_errors.extend(errors)
+                            }
+                        }
+
+                        KeywordKind::Import => {
+                            match self.parse_import(&mut iter) {
+                                Success(import) => schema.imports.push(import),
+                                Errors(errors) => all_errors.extend(errors)
+                            }
+                        }
+
+                        KeywordKind::Enum => {
+                            match self.parse_enum(&mut iter, EnumKind::Enum, doc) {
+                                Success(value) => schema.enums.push(value),
+                                Errors(errors) => all_errors.extend(errors)
+                            }
+                        }
+
+                        KeywordKind::Const => {
+                            match self.parse_const(&mut iter, doc) {
+                                Success(constant) => schema.constants.push(constant),
+                                Errors(errors) => all_errors.extend(errors)
+                            }
+                        }
+
+                        KeywordKind::Namespace => {
+
+                            let token = (*token).clone();
+
+                            if schema.namespace.is_some() {
+                                let err = ParserError {
+                                    message: "Namespace is already declared".to_string(),
+                                    token: Some(token.clone())
+                                };
+                                all_errors.push(Box::new(err));
+                            } else if !schema.packets.is_empty() || !schema.templates.is_empty() || !schema.enums.is_empty() || !schema.constants.is_empty() || !schema.aliases.is_empty() || !schema.unions.is_empty() || !schema.transitions.is_empty() || !schema.services.is_empty() {
+                                let err = ParserError {
+                                    message: "Namespace must be declared before any declaration".to_string(),
+                                    token: Some(token.clone())
+                                };
+                                all_errors.push(Box::new(err));
+                            }
+
+                            match self.parse_namespace(&mut iter) {
+                                Success(namespace) => schema.namespace = Some(namespace),
                                 Errors(errors) => all_errors.extend(errors)
                             }
                         }
 
                         _ => {
                             let err = ParserError {
-                                message: format!("Expected keyword 'packet', but got keyword '{}'", &token.kind),
+                                message: format!("Expected declaration keyword, but got keyword '{}'", &token.kind),
                                 token: Some((*token).clone())
                             };
                             all_errors.push(Box::new(err));
@@ -460,9 +1742,96 @@ impl AstParser for SimpleParser {
                     }
 
                 },
+                Qualifier(qualifier) if qualifier == "type" => {
+                    match self.parse_type_alias(&mut iter, doc) {
+                        Success(alias) => schema.aliases.push(alias),
+                        Errors(errors) => all_errors.extend(errors)
+                    }
+                }
+                Qualifier(qualifier) if qualifier == "flags" => {
+                    match self.parse_enum(&mut iter, EnumKind::Flags, doc) {
+                        Success(value) => schema.enums.push(value),
+                        Errors(errors) => all_errors.extend(errors)
+                    }
+                }
+                Qualifier(qualifier) if qualifier == "direction" => {
+                    if doc.is_some() {
+                        let err = ParserError {
+                            message: "Direction blocks can not have doc comments".to_string(),
+                            token: Some((*token).clone())
+                        };
+                        all_errors.push(Box::new(err));
+                    }
+                    match self.parse_direction_block(&mut iter) {
+                        Success(packets) => schema.packets.extend(packets),
+                        Errors(errors) => all_errors.extend(errors)
+                    }
+                }
+                Qualifier(qualifier) if qualifier == "state" => {
+                    if doc.is_some() {
+                        let err = ParserError {
+                            message: "State blocks can not have doc comments".to_string(),
+                            token: Some((*token).clone())
+                        };
+                        all_errors.push(Box::new(err));
+                    }
+                    match self.parse_state_block(&mut iter) {
+                        Success((state, packets)) => {
+                            if !schema.states.contains(&state) {
+                                schema.states.push(state);
+                            }
+                            schema.packets.extend(packets);
+                        }
+                        Errors(errors) => all_errors.extend(errors)
+                    }
+                }
+                Qualifier(qualifier) if qualifier == "service" => {
+                    match self.parse_service(&mut iter, doc) {
+                        Success(service) => schema.services.push(service),
+                        Errors(errors) => all_errors.extend(errors)
+                    }
+                }
+                Qualifier(qualifier) if qualifier == "reserved" => {
+                    match self.parse_reserved(&mut iter) {
+                        Success(reserved) => {
+                            schema.reserved.ids.extend(reserved.ids);
+                            schema.reserved.names.extend(reserved.names);
+                        }
+                        Errors(errors) => all_errors.extend(errors)
+                    }
+                }
+                Qualifier(qualifier) if qualifier == "version" => {
+                    if schema.version.is_some() {
+                        let err = ParserError {
+                            message: "Version is already declared".to_string(),
+                            token: Some((*token).clone())
+                        };
+                        all_errors.push(Box::new(err));
+                    }
+                    match self.parse_version(&mut iter) {
+                        Success(version) => schema.version = Some(version),
+                        Errors(errors) => all_errors.extend(errors)
+                    }
+                }
+                Qualifier(qualifier) if qualifier == "transition" => {
+                    match self.parse_transition(&mut iter) {
+                        Success(transition) => schema.transitions.push(transition),
+                        Errors(errors) => all_errors.extend(errors)
+                    }
+                }
+                Qualifier(qualifier) if is_direction => {
+                    let direction = Direction::from_name(qualifier);
+                    iter.next();
+                    if let Some(direction) = direction {
+                        match self.parse_directed_packet(&mut iter, direction, doc, attributes) {
+                            Success(packet) => schema.packets.push(packet),
+                            Errors(errors) => all_errors.extend(errors)
+                        }
+                    }
+                }
                 _ => {
                     let err = ParserError {
-                        message: format!("Expected keyword 'packet', but got '{}'", &token.kind),
+                        message: format!("Expected declaration keyword, but got '{}'", &token.kind),
                         token: Some((*token).clone())
                     };
                     all_errors.push(Box::new(err));
@@ -471,11 +1840,87 @@ impl AstParser for SimpleParser {
             }
         }
 
+        for packet in &mut schema.packets {
+            packet.namespace = schema.namespace.clone();
+            packet.version = schema.version;
+        }
+
+        for template in &mut schema.templates {
+            template.namespace = schema.namespace.clone();
+            template.version = schema.version;
+        }
+
+        for enum_decl in &mut schema.enums {
+            enum_decl.namespace = schema.namespace.clone();
+        }
+
+        for constant in &mut schema.constants {
+            constant.namespace = schema.namespace.clone();
+        }
+
+        for alias in &mut schema.aliases {
+            alias.namespace = schema.namespace.clone();
+        }
+
+        for union in &mut schema.unions {
+            union.namespace = schema.namespace.clone();
+            union.version = schema.version;
+        }
+
+        for transition in &mut schema.transitions {
+            transition.namespace = schema.namespace.clone();
+        }
+
+        for service in &mut schema.services {
+            service.namespace = schema.namespace.clone();
+        }
+
+        schema.reserved.names = schema.reserved.names.iter()
+            .map(|name| qualify_name(&schema.namespace, name))
+            .collect();
+
         if all_errors.is_empty() {
-            Success(packets)
+            Success(schema)
         } else {
             Errors(all_errors)
         }
     }
 
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::test_utils::parse_schema;
+
+    fn field_types(source: &str) -> Vec<(String, FieldType)> {
+        parse_schema(source).packets[0].fields.iter()
+            .map(|field| (field.name.clone(), field.typ.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_contextual_keywords_as_types_and_field_names() {
+
+        let cases = [
+            ("optional", "optional string value", Optional(Box::new(FieldType::Str))),
+            ("map", "map(max_items = 4) string i32 value", Map(Box::new(FieldType::Str), Box::new(FieldType::I32))),
+            ("set", "set(max_items = 8) string value", Set(Box::new(FieldType::Str))),
+            ("bytes", "bytes(max_len = 64) value", FieldType::Bytes),
+        ];
+
+        for (keyword, declaration, expected) in cases {
+
+            let fields = field_types(format!(
+                "packet Login {{\n id = 1\n fields {{\n {}\n i32 {}\n }}\n}}", declaration, keyword
+            ).as_str());
+
+            assert_eq!(fields, vec![
+                ("value".to_string(), expected),
+                (keyword.to_string(), FieldType::I32),
+            ], "{}", keyword);
+        }
+    }
+
+}
@@ -0,0 +1,175 @@
+use crate::core::semantics::semantic_analyzer::{SemanticAnalyzer, SemanticWarning};
+use crate::core::{find_attribute, Attribute, Field, FieldType, Literal, Schema, SemanticError};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{MorphError, MorphResult};
+
+pub struct AttributeSemanticAnalyzer;
+
+impl AttributeSemanticAnalyzer {
+
+    fn analyze_attributes(&self, target: &str, attributes: &[Attribute], all_errors: &mut Vec<Box<dyn MorphError>>) {
+
+        let mut existing_names: Vec<String> = Vec::new();
+
+        for attribute in attributes {
+
+            if existing_names.contains(&attribute.name) {
+                let err = SemanticError {
+                    message: format!("Duplicate attribute '@{}' on {}", attribute.name, target),
+                };
+                all_errors.push(Box::new(err));
+                continue;
+            }
+
+            existing_names.push(attribute.name.clone());
+
+            if let Err(message) = self.check_attribute(attribute) {
+                let err = SemanticError {
+                    message: format!("Attribute '@{}' on {} {}", attribute.name, target, message),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+        }
+
+    }
+
+    fn check_attribute(&self, attribute: &Attribute) -> Result<(), String> {
+
+        let args = &attribute.args;
+
+        match attribute.name.as_str() {
+            "deprecated" => match (args.len(), attribute.positional_arg(0)) {
+                (0, _) | (1, Some(Literal::Str(_))) => Ok(()),
+                _ => Err("expects an optional message string".to_string()),
+            },
+            "since" | "until" => match (args.len(), attribute.positional_arg(0)) {
+                (1, Some(Literal::Integer(version))) if *version >= 0 => Ok(()),
+                _ => Err("expects a non-negative version number".to_string()),
+            },
+            "java" => match (args.len(), attribute.arg("name")) {
+                (1, Some(Literal::Str(name))) if is_java_identifier(name) => Ok(()),
+                _ => Err("expects a Java identifier as 'name'".to_string()),
+            },
+            _ => Err("is unknown".to_string()),
+        }
+
+    }
+
+    fn deprecation_warnings(
+        &self,
+        user: &str,
+        field: &Field,
+        field_type: &FieldType,
+        context: &Schema,
+        warnings: &mut Vec<SemanticWarning>,
+    ) {
+        match field_type {
+            FieldType::Nested(name) => {
+
+                let Some(packet) = context.find_packet(name) else {
+                    return;
+                };
+
+                let Some(deprecated) = find_attribute(&packet.attributes, "deprecated") else {
+                    return;
+                };
+
+                let mut message = format!("Field '{}' in {} uses deprecated {} '{}'", field.name, user, packet.kind, packet.name);
+                if let Some(Literal::Str(note)) = deprecated.positional_arg(0) {
+                    message.push_str(format!(": {}", note).as_str());
+                }
+
+                warnings.push(SemanticWarning { message });
+
+            }
+            FieldType::Array(inner_type) | FieldType::FixedArray(inner_type, _) | FieldType::Set(inner_type) | FieldType::Optional(inner_type) => {
+                self.deprecation_warnings(user, field, inner_type, context, warnings);
+            }
+            FieldType::Map(_, value_type) => {
+                self.deprecation_warnings(user, field, value_type, context, warnings);
+            }
+            _ => {}
+        }
+    }
+
+}
+
+fn is_java_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' || first == '$' => {
+            chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$')
+        }
+        _ => false,
+    }
+}
+
+impl SemanticAnalyzer for AttributeSemanticAnalyzer {
+
+    fn analyze(&self, schema: &Schema, _context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        for packet in &schema.packets {
+
+            let target = format!("{} '{}'", packet.kind, packet.name);
+            self.analyze_attributes(target.as_str(), &packet.attributes, &mut all_errors);
+
+            for field in &packet.fields {
+                let target = format!("field '{}' in {} '{}'", field.name, packet.kind, packet.name);
+                self.analyze_attributes(target.as_str(), &field.attributes, &mut all_errors);
+            }
+
+        }
+
+        for union in &schema.unions {
+            for variant in &union.variants {
+                let target = format!("variant '{}' in union '{}'", variant.name, union.name);
+                self.analyze_attributes(target.as_str(), &variant.attributes, &mut all_errors);
+            }
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+    fn warnings(&self, schema: &Schema, context: &Schema) -> Vec<SemanticWarning> {
+
+        let mut warnings: Vec<SemanticWarning> = Vec::new();
+
+        for packet in &schema.packets {
+
+            if find_attribute(&packet.attributes, "deprecated").is_some() {
+                continue;
+            }
+
+            let user = format!("{} '{}'", packet.kind, packet.name);
+
+            for field in &packet.fields {
+                self.deprecation_warnings(user.as_str(), field, &field.typ, context, &mut warnings);
+            }
+
+        }
+
+        for union in &schema.unions {
+
+            let user = format!("union '{}'", union.name);
+
+            for variant in &union.variants {
+                self.deprecation_warnings(user.as_str(), variant, &variant.typ, context, &mut warnings);
+            }
+
+        }
+
+        warnings
+
+    }
+
+}
@@ -1,5 +1,5 @@
-use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
-use crate::core::Packet;
+use crate::core::semantics::semantic_analyzer::{SemanticAnalyzer, SemanticWarning};
+use crate::core::Schema;
 use crate::utils::MorphResult::{Errors, Success};
 use crate::utils::MorphResult;
 
@@ -21,10 +21,10 @@ impl CompositeSemanticAnalyzer {
 
 impl SemanticAnalyzer for CompositeSemanticAnalyzer {
 
-    fn analyze(&self, packets: &Vec<Packet>) -> MorphResult<()> {
+    fn analyze(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
 
         for analyzer in &self.analyzers {
-            if let Errors(errors) = analyzer.analyze(packets) {
+            if let Errors(errors) = analyzer.analyze(schema, context) {
                 return Errors(errors);
             }
         }
@@ -33,4 +33,10 @@ impl SemanticAnalyzer for CompositeSemanticAnalyzer {
 
     }
 
+    fn warnings(&self, schema: &Schema, context: &Schema) -> Vec<SemanticWarning> {
+        self.analyzers.iter()
+            .flat_map(|analyzer| analyzer.warnings(schema, context))
+            .collect()
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,61 @@
+use crate::core::semantics::semantic_analyzer::{imported, SemanticAnalyzer};
+use crate::core::{check_literal, Constant, FieldType, Schema, SemanticError};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{MorphError, MorphResult};
+
+pub struct ConstantSemanticAnalyzer;
+
+impl SemanticAnalyzer for ConstantSemanticAnalyzer {
+
+    fn analyze(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+        let mut existing_names: Vec<String> = imported(&context.constants, &schema.constants, Constant::qualified_name)
+            .into_iter()
+            .map(|constant| constant.qualified_name())
+            .collect();
+
+        for constant in &schema.constants {
+
+            let constant_name = constant.qualified_name();
+
+            if existing_names.contains(&constant_name) {
+                let err = SemanticError {
+                    message: format!("Duplicate constant name: {}", constant_name),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+            existing_names.push(constant_name);
+
+            if !constant.typ.is_primitive() && constant.typ != FieldType::Str {
+                let err = SemanticError {
+                    message: format!(
+                        "Type of constant '{}' must be a primitive or string, actual type is {}",
+                        constant.name, constant.typ
+                    ),
+                };
+                all_errors.push(Box::new(err));
+                continue;
+            }
+
+            if let Err(reason) = check_literal(context, &constant.typ, &constant.value) {
+                let err = SemanticError {
+                    message: format!("Value {} of constant '{}' {}", constant.value, constant.name, reason),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
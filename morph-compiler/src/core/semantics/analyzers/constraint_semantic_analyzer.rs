@@ -0,0 +1,189 @@
+use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
+use crate::core::{Constraint, ConstraintKind, Field, FieldType, Literal, Schema, SemanticError};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{MorphError, MorphResult};
+use std::cmp::Ordering;
+
+pub struct ConstraintSemanticAnalyzer;
+
+impl ConstraintSemanticAnalyzer {
+
+    fn analyze_field(&self, owner: &str, field: &Field, all_errors: &mut Vec<Box<dyn MorphError>>) {
+
+        let field_type = match &field.typ {
+            FieldType::Optional(inner_type) => inner_type.as_ref(),
+            field_type => field_type,
+        };
+
+        let mut existing_kinds: Vec<ConstraintKind> = Vec::new();
+
+        for constraint in &field.constraints {
+
+            if existing_kinds.contains(&constraint.kind) {
+                let err = SemanticError {
+                    message: format!("Duplicate constraint '{}' on field '{}' in {}", constraint.kind, field.name, owner),
+                };
+                all_errors.push(Box::new(err));
+                continue;
+            }
+
+            existing_kinds.push(constraint.kind.clone());
+
+            if let Err(message) = self.check_constraint(field_type, constraint) {
+                let err = SemanticError {
+                    message: format!("Constraint '{}' of field '{}' in {} {}", constraint.kind, field.name, owner, message),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+        }
+
+        let bounds = [
+            (ConstraintKind::Min, ConstraintKind::Max),
+            (ConstraintKind::MinLen, ConstraintKind::MaxLen),
+            (ConstraintKind::MinItems, ConstraintKind::MaxItems),
+        ];
+
+        for (lower_kind, upper_kind) in bounds {
+
+            let lower = field.constraints.iter().find(|c| c.kind == lower_kind);
+            let upper = field.constraints.iter().find(|c| c.kind == upper_kind);
+
+            if let (Some(lower), Some(upper)) = (lower, upper)
+                && compare_literals(&lower.value, &upper.value) == Some(Ordering::Greater) {
+                let err = SemanticError {
+                    message: format!(
+                        "Constraint '{}' of field '{}' in {} is greater than '{}': {} > {}",
+                        lower_kind, field.name, owner, upper_kind, lower.value, upper.value
+                    ),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+        }
+
+    }
+
+    fn check_constraint(&self, field_type: &FieldType, constraint: &Constraint) -> Result<(), String> {
+
+        let applicable = match constraint.kind {
+            ConstraintKind::Min | ConstraintKind::Max => {
+                field_type.integer_range().is_some() || matches!(field_type, FieldType::F32 | FieldType::F64)
+            }
+            ConstraintKind::MinLen | ConstraintKind::MaxLen => {
+                matches!(field_type, FieldType::Str | FieldType::Bytes)
+            }
+            ConstraintKind::MinItems | ConstraintKind::MaxItems => {
+                matches!(field_type, FieldType::Array(_) | FieldType::Set(_) | FieldType::Map(_, _))
+            }
+        };
+
+        if !applicable {
+            return Err(format!("can not be applied to type {}", field_type));
+        }
+
+        match (&constraint.kind, &constraint.value) {
+            (ConstraintKind::Min | ConstraintKind::Max, Literal::Integer(value)) => {
+                match field_type.integer_range() {
+                    Some((min, max)) if *value < min || *value > max => {
+                        Err(format!("is out of range for type {}: {}", field_type, value))
+                    }
+                    _ => Ok(()),
+                }
+            }
+            (ConstraintKind::Min | ConstraintKind::Max, Literal::Float(_)) if field_type.integer_range().is_none() => {
+                Ok(())
+            }
+            (ConstraintKind::Min | ConstraintKind::Max, value) => {
+                Err(format!("must be a number of type {}, actual value is {}", field_type, value))
+            }
+            (_, Literal::Integer(value)) if *value >= 0 && i32::try_from(*value).is_ok() => {
+                Ok(())
+            }
+            (_, value) => {
+                Err(format!("must be a non-negative integer, actual value is {}", value))
+            }
+        }
+
+    }
+
+}
+
+fn compare_literals(left: &Literal, right: &Literal) -> Option<Ordering> {
+    match (left, right) {
+        (Literal::Integer(left), Literal::Integer(right)) => Some(left.cmp(right)),
+        (Literal::Integer(left), Literal::Float(right)) => (*left as f64).partial_cmp(right),
+        (Literal::Float(left), Literal::Integer(right)) => left.partial_cmp(&(*right as f64)),
+        (Literal::Float(left), Literal::Float(right)) => left.partial_cmp(right),
+        _ => None,
+    }
+}
+
+impl SemanticAnalyzer for ConstraintSemanticAnalyzer {
+
+    fn analyze(&self, schema: &Schema, _context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        for packet in &schema.packets {
+
+            let owner = format!("{} '{}'", packet.kind, packet.name);
+
+            for field in &packet.fields {
+                self.analyze_field(owner.as_str(), field, &mut all_errors);
+            }
+
+        }
+
+        for union in &schema.unions {
+
+            let owner = format!("union '{}'", union.name);
+
+            for variant in &union.variants {
+                self.analyze_field(owner.as_str(), variant, &mut all_errors);
+            }
+
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::test_utils::{error_messages, parse_schema};
+
+    #[test]
+    fn checks_bytes_length_constraints() {
+
+        let schema = parse_schema(r#"
+            packet Upload {
+                id = 1
+                fields {
+                    bytes(max_len = 64) payload
+                    optional bytes(max_len = 16) signature
+                    bytes(min_len = 8, max_len = 4) inverted
+                    bytes(max_len = -1) negative
+                    bytes(max = 3) ranged
+                }
+            }
+        "#);
+
+        assert_eq!(error_messages(ConstraintSemanticAnalyzer.analyze(&schema, &schema)), vec![
+            "SemanticError: Constraint 'min_len' of field 'inverted' in packet 'Upload' is greater than 'max_len': 8 > 4",
+            "SemanticError: Constraint 'max_len' of field 'negative' in packet 'Upload' must be a non-negative integer, actual value is -1",
+            "SemanticError: Constraint 'max' of field 'ranged' in packet 'Upload' can not be applied to type bytes",
+        ]);
+    }
+
+}
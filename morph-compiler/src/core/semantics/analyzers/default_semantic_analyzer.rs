@@ -0,0 +1,94 @@
+use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
+use crate::core::{FieldType, Literal, Schema, SemanticError};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{MorphError, MorphResult};
+
+pub struct DefaultSemanticAnalyzer;
+
+pub fn check_literal(schema: &Schema, field_type: &FieldType, literal: &Literal) -> Result<(), String> {
+
+    let is_valid = match (field_type, literal) {
+        (FieldType::Optional(inner_type), _) => {
+            return check_literal(schema, inner_type, literal);
+        }
+        (FieldType::Bool, Literal::Bool(_)) => true,
+        (FieldType::F32, Literal::Float(value)) => value.abs() <= f32::MAX as f64,
+        (FieldType::F32 | FieldType::F64, Literal::Float(_) | Literal::Integer(_)) => true,
+        (FieldType::Str, Literal::Str(_)) => true,
+        (FieldType::Char, Literal::Str(value)) => {
+            let mut chars = value.chars();
+            matches!((chars.next(), chars.next()), (Some(ch), None) if (ch as u32) <= 0xFFFF)
+        }
+        (_, Literal::Integer(value)) => {
+            match field_type.integer_range() {
+                Some((min, max)) => {
+                    if *value < min || *value > max {
+                        return Err(format!("does not fit in {}", field_type));
+                    }
+                    true
+                }
+                None => false,
+            }
+        }
+        (FieldType::Nested(type_name), Literal::Reference(variant_name)) => {
+            match schema.find_enum(type_name) {
+                Some(enum_decl) => {
+                    if !enum_decl.variants.iter().any(|variant| variant.name == *variant_name) {
+                        return Err(format!("is not a variant of {} '{}'", enum_decl.kind, enum_decl.name));
+                    }
+                    true
+                }
+                None => false,
+            }
+        }
+        (_, Literal::Reference(_)) => {
+            return Err("is not a known constant".to_string());
+        }
+        _ => false,
+    };
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(format!("does not match type {}", field_type))
+    }
+
+}
+
+impl SemanticAnalyzer for DefaultSemanticAnalyzer {
+
+    fn analyze(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        for packet in &schema.packets {
+            for field in &packet.fields {
+
+                let Some(default) = &field.default else {
+                    continue;
+                };
+
+                if let Err(reason) = check_literal(context, &field.typ, default) {
+                    let err = SemanticError {
+                        message: format!(
+                            "Default value {} of field '{}' in {} '{}' {}",
+                            default, field.name, packet.kind, packet.name, reason
+                        ),
+                    };
+                    all_errors.push(Box::new(err));
+                }
+
+            }
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
@@ -1,6 +1,6 @@
 use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
-use crate::core::FieldType::{Array, Nested};
-use crate::core::{DependencyGraph, DependencyResolvingError, Dependent, DependentGraphBuilder, Packet};
+use crate::core::FieldType::{Array, FixedArray, Generic, Map, Nested, Optional, Set, Union};
+use crate::core::{DependencyGraph, DependencyResolvingError, Dependent, DependentGraphBuilder, Field, FieldType, Packet, Schema};
 use crate::utils::MorphResult::{Errors, Success};
 use crate::utils::{MorphError, MorphResult};
 use std::collections::HashSet;
@@ -27,21 +27,25 @@ pub struct DependencySemanticAnalyzer;
 
 impl DependencySemanticAnalyzer {
 
-    fn analyze_dependencies(&self, packets: &Vec<Packet>) -> MorphResult<()> {
+    fn analyze_dependencies(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
 
-        let graph = match self.build_dependency_graph(packets) {
+        let graph = match self.build_dependency_graph(schema, context) {
             Success(graph) => graph,
             Errors(errors) => return Errors(errors)
         };
 
-        match graph.find_cycle() {
-            Some(cycle) => {
-                let err = DependencyResolvingError {
-                    message: format!("Cycle detected: {}", cycle),
-                };
-                return Errors(vec![Box::new(err)]);
-            }
-            None => {}
+        let own_ids = self.collect_existing_ids(schema);
+
+        let own_cycle = graph.nodes.keys()
+            .filter(|id| own_ids.contains(*id))
+            .filter_map(|id| graph.find_cycle_from_node(id))
+            .find(|cycle| cycle.nodes.iter().any(|id| own_ids.contains(id)));
+
+        if let Some(cycle) = own_cycle {
+            let err = DependencyResolvingError {
+                message: format!("Cycle detected: {}", cycle),
+            };
+            return Errors(vec![Box::new(err)]);
         }
 
         //self.print_graph_dependencies(&graph);
@@ -50,33 +54,68 @@ impl DependencySemanticAnalyzer {
 
     }
 
-    fn build_dependency_graph(&self, packets: &Vec<Packet>) -> MorphResult<DependencyGraph<DependentField>> {
+    fn build_dependency_graph(&self, schema: &Schema, context: &Schema) -> MorphResult<DependencyGraph<DependentField>> {
         let mut builder = DependentGraphBuilder::<DependentField>::new();
 
-        let existing_ids = self.collect_existing_ids(packets);
+        let existing_ids = self.collect_existing_ids(context);
+        let own_ids = self.collect_existing_ids(schema);
 
-        for packet in packets {
+        for packet in context.packets.iter().chain(context.templates.iter()) {
             self.add_packet_node(&mut builder, packet);
         }
 
-        for packet in packets {
-            match self.add_packet_dependencies(&mut builder, packet, &existing_ids) {
-                Success(_) => {}
-                Errors(errors) => return Errors(errors),
+        for enum_decl in &context.enums {
+            builder.node(DependentField::new(enum_decl.qualified_name()));
+        }
+
+        for union in &context.unions {
+            builder.node(DependentField::new(union.qualified_name()));
+        }
+
+        for packet in context.packets.iter().chain(context.templates.iter()) {
+            if let Errors(errors) = self.add_packet_dependencies(&mut builder, packet, &existing_ids)
+                && own_ids.contains(&packet.qualified_name()) {
+                return Errors(errors);
+            }
+        }
+
+        for service in &context.services {
+            let is_own = schema.services.iter().any(|own| own.qualified_name() == service.qualified_name());
+            let current_id = DependentField::new(service.qualified_name()).dependent_id().to_string();
+            builder.node(DependentField::new(service.qualified_name()));
+            for rpc in &service.methods {
+                for type_name in [&rpc.request, &rpc.response] {
+                    if let Errors(errors) = self.add_graph_dependency(&mut builder, current_id.as_str(), type_name, &existing_ids)
+                        && is_own {
+                        return Errors(errors);
+                    }
+                }
+            }
+        }
+
+        for union in &context.unions {
+            let current_id = DependentField::new(union.qualified_name()).dependent_id().to_string();
+            if let Errors(errors) = self.add_fields_dependencies(&mut builder, current_id.as_str(), &union.variants, &[], &existing_ids)
+                && own_ids.contains(&current_id) {
+                return Errors(errors);
             }
         }
 
         Success(builder.build())
     }
 
-    fn collect_existing_ids(&self, packets: &Vec<Packet>) -> HashSet<String> {
-        packets.iter()
-            .map(|p| DependentField::new(p.name.to_string()).dependent_id().to_string())
+    fn collect_existing_ids(&self, schema: &Schema) -> HashSet<String> {
+        schema.packets.iter()
+            .chain(schema.templates.iter())
+            .map(|p| p.qualified_name())
+            .chain(schema.enums.iter().map(|e| e.qualified_name()))
+            .chain(schema.unions.iter().map(|u| u.qualified_name()))
+            .map(|name| DependentField::new(name).dependent_id().to_string())
             .collect()
     }
 
     fn add_packet_node(&self, builder: &mut DependentGraphBuilder<DependentField>, packet: &Packet) {
-        let node = DependentField::new(packet.name.to_string());
+        let node = DependentField::new(packet.qualified_name());
         builder.node(node);
     }
 
@@ -87,27 +126,31 @@ impl DependencySemanticAnalyzer {
         existing_ids: &HashSet<String>,
     ) -> MorphResult<()> {
 
-        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+        let current_id = DependentField::new(packet.qualified_name()).dependent_id().to_string();
+
+        if let Some(parent) = &packet.parent
+            && let Errors(errors) = self.add_graph_dependency(builder, current_id.as_str(), parent.as_str(), existing_ids) {
+            return Errors(errors);
+        }
 
-        let current_id = DependentField::new(packet.name.to_string()).dependent_id().to_string();
+        self.add_fields_dependencies(builder, current_id.as_str(), &packet.fields, &packet.type_params, existing_ids)
 
-        for field in &packet.fields {
-            match &field.typ {
-                Nested(type_name) => {
-                    match self.add_graph_dependency(builder, current_id.as_str(), type_name.as_str(), existing_ids) {
-                        Success(_) => {}
-                        Errors(errors) => all_errors.extend(errors),
-                    }
-                }
-                Array(inner_type) => {
-                    if let Nested(type_name) = inner_type.as_ref() {
-                        match self.add_graph_dependency(builder, current_id.as_str(), type_name.as_str(), existing_ids) {
-                            Success(_) => {}
-                            Errors(errors) => all_errors.extend(errors),
-                        }
-                    }
-                }
-                _ => {}
+    }
+
+    fn add_fields_dependencies(
+        &self,
+        builder: &mut DependentGraphBuilder<DependentField>,
+        current_id: &str,
+        fields: &[Field],
+        type_params: &[String],
+        existing_ids: &HashSet<String>,
+    ) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        for field in fields {
+            if let Errors(errors) = self.add_type_dependencies(builder, current_id, &field.typ, type_params, existing_ids) {
+                all_errors.extend(errors);
             }
         }
 
@@ -121,6 +164,40 @@ impl DependencySemanticAnalyzer {
 
     }
 
+    fn add_type_dependencies(
+        &self,
+        builder: &mut DependentGraphBuilder<DependentField>,
+        current_id: &str,
+        field_type: &FieldType,
+        type_params: &[String],
+        existing_ids: &HashSet<String>,
+    ) -> MorphResult<()> {
+        match field_type {
+            Nested(type_name) if type_params.contains(type_name) => Success(()),
+            Nested(type_name) | Union(type_name) => {
+                self.add_graph_dependency(builder, current_id, type_name.as_str(), existing_ids)
+            }
+            Generic(type_name, args) => {
+                if let Errors(errors) = self.add_graph_dependency(builder, current_id, type_name.as_str(), existing_ids) {
+                    return Errors(errors);
+                }
+                for arg in args {
+                    if let Errors(errors) = self.add_type_dependencies(builder, current_id, arg, type_params, existing_ids) {
+                        return Errors(errors);
+                    }
+                }
+                Success(())
+            }
+            Array(inner_type) | FixedArray(inner_type, _) | Set(inner_type) | Optional(inner_type) => {
+                self.add_type_dependencies(builder, current_id, inner_type, type_params, existing_ids)
+            }
+            Map(_, value_type) => {
+                self.add_type_dependencies(builder, current_id, value_type, type_params, existing_ids)
+            }
+            _ => Success(())
+        }
+    }
+
     fn add_graph_dependency(
         &self,
         builder: &mut DependentGraphBuilder<DependentField>,
@@ -174,11 +251,11 @@ impl DependencySemanticAnalyzer {
 
 impl SemanticAnalyzer for DependencySemanticAnalyzer {
 
-    fn analyze(&self, packets: &Vec<Packet>) -> MorphResult<()> {
+    fn analyze(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
 
         let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
 
-        match self.analyze_dependencies(packets) {
+        match self.analyze_dependencies(schema, context) {
             Errors(errors) => all_errors.extend(errors),
             _ => {}
         }
@@ -0,0 +1,106 @@
+use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
+use crate::core::{Enum, EnumKind, Schema, SemanticError};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{capitalize, MorphError, MorphResult};
+
+pub struct EnumSemanticAnalyzer;
+
+impl EnumSemanticAnalyzer {
+
+    fn analyze_enum(&self, enum_decl: &Enum, all_errors: &mut Vec<Box<dyn MorphError>>) {
+
+        let (Some((min, max)), Some(bit_width)) = (enum_decl.typ.integer_range(), enum_decl.typ.bit_width()) else {
+            let err = SemanticError {
+                message: format!(
+                    "Backing type of {} '{}' must be an integer type, actual type is {}",
+                    enum_decl.kind, enum_decl.name, enum_decl.typ
+                ),
+            };
+            all_errors.push(Box::new(err));
+            return;
+        };
+
+        if enum_decl.variants.is_empty() {
+            let err = SemanticError {
+                message: format!(
+                    "{} '{}' must declare at least one variant",
+                    capitalize(enum_decl.kind.to_string().as_str()), enum_decl.name
+                ),
+            };
+            all_errors.push(Box::new(err));
+        }
+
+        let mut existing_names: Vec<String> = Vec::new();
+        let mut existing_values: Vec<i32> = Vec::new();
+
+        for variant in &enum_decl.variants {
+
+            if existing_names.contains(&variant.name) {
+                let err = SemanticError {
+                    message: format!("Duplicate variant name in {} '{}': {}", enum_decl.kind, enum_decl.name, variant.name),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+            if existing_values.contains(&variant.value) {
+                let err = SemanticError {
+                    message: format!(
+                        "Duplicate variant value in {} '{}': {} = {}",
+                        enum_decl.kind, enum_decl.name, variant.name, variant.value
+                    ),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+            let value = variant.value as i64;
+            match enum_decl.kind {
+                EnumKind::Enum if value < min || value > max => {
+                    let err = SemanticError {
+                        message: format!(
+                            "Value of variant '{}' in enum '{}' does not fit in {}, actual value is {}",
+                            variant.name, enum_decl.name, enum_decl.typ, variant.value
+                        ),
+                    };
+                    all_errors.push(Box::new(err));
+                }
+                EnumKind::Flags if value < 0 || value >= bit_width as i64 => {
+                    let err = SemanticError {
+                        message: format!(
+                            "Bit of flag '{}' in flags '{}' does not fit in {}, actual bit is {}",
+                            variant.name, enum_decl.name, enum_decl.typ, variant.value
+                        ),
+                    };
+                    all_errors.push(Box::new(err));
+                }
+                _ => {}
+            }
+
+            existing_names.push(variant.name.to_string());
+            existing_values.push(variant.value);
+        }
+
+    }
+
+}
+
+impl SemanticAnalyzer for EnumSemanticAnalyzer {
+
+    fn analyze(&self, schema: &Schema, _context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        for enum_decl in &schema.enums {
+            self.analyze_enum(enum_decl, &mut all_errors);
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
@@ -1,32 +1,124 @@
 use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
-use crate::core::{Packet, SemanticError};
+use crate::core::{Field, FieldType, Schema, SemanticError};
 use crate::utils::MorphResult::{Errors, Success};
 use crate::utils::{MorphError, MorphResult};
 
 pub struct FieldSemanticAnalyzer;
 
+impl FieldSemanticAnalyzer {
+
+    fn analyze_field_type(
+        &self,
+        context: &Schema,
+        owner: &str,
+        field: &Field,
+        field_type: &FieldType,
+        all_errors: &mut Vec<Box<dyn MorphError>>,
+    ) {
+        match field_type {
+            FieldType::Optional(inner_type) => {
+                if let FieldType::Optional(_) = inner_type.as_ref() {
+                    let err = SemanticError {
+                        message: format!("Field '{}' in {} can not be optional twice", field.name, owner),
+                    };
+                    all_errors.push(Box::new(err));
+                }
+                self.analyze_field_type(context, owner, field, inner_type, all_errors);
+            }
+            FieldType::Array(inner_type) | FieldType::FixedArray(inner_type, _) => {
+                self.analyze_field_type(context, owner, field, inner_type, all_errors);
+            }
+            FieldType::Set(inner_type) => {
+                let is_enum = matches!(inner_type.as_ref(), FieldType::Nested(name) if context.find_enum(name).is_some());
+                if !inner_type.is_primitive() && **inner_type != FieldType::Str && !is_enum {
+                    let err = SemanticError {
+                        message: format!(
+                            "Set element of field '{}' in {} must be a primitive, string or enum, actual type is {}",
+                            field.name, owner, inner_type
+                        ),
+                    };
+                    all_errors.push(Box::new(err));
+                }
+            }
+            FieldType::Map(key_type, value_type) => {
+                if !key_type.is_primitive() && **key_type != FieldType::Str {
+                    let err = SemanticError {
+                        message: format!(
+                            "Map key of field '{}' in {} must be a primitive or string, actual type is {}",
+                            field.name, owner, key_type
+                        ),
+                    };
+                    all_errors.push(Box::new(err));
+                }
+                self.analyze_field_type(context, owner, field, value_type, all_errors);
+            }
+            _ => {}
+        }
+    }
+
+}
+
 impl SemanticAnalyzer for FieldSemanticAnalyzer {
 
-    fn analyze(&self, packets: &Vec<Packet>) -> MorphResult<()> {
+    fn analyze(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
 
         let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
-        let mut existing_names: Vec<String> = Vec::new();
 
-        for packet in packets {
+        for packet in &schema.packets {
+
+            let owner = format!("{} '{}'", packet.kind, packet.name);
+            let mut existing_names: Vec<String> = Vec::new();
+            let mut reserved_names: Vec<&String> = packet.reserved_names.iter().collect();
+
+            for ancestor in context.ancestors(packet) {
+                for field in &ancestor.fields {
+                    existing_names.push(field.name.to_string());
+                }
+                reserved_names.extend(ancestor.reserved_names.iter());
+            }
+
+            let inherited_count = existing_names.len();
 
             for field in &packet.fields {
 
                 let field_name = field.name.to_string();
 
-                if existing_names.contains(&field_name) {
+                if existing_names[..inherited_count].contains(&field_name) {
                     let err = SemanticError {
-                        message: format!("Duplicate field name in packet '{}': {}", packet.name, field_name),
+                        message: format!(
+                            "Field '{}' in {} '{}' is already declared in a parent {}",
+                            field_name, packet.kind, packet.name, packet.kind
+                        ),
+                    };
+                    all_errors.push(Box::new(err));
+                } else if existing_names.contains(&field_name) {
+                    let err = SemanticError {
+                        message: format!("Duplicate field name in {} '{}': {}", packet.kind, packet.name, field_name),
+                    };
+                    all_errors.push(Box::new(err));
+                }
+
+                if reserved_names.contains(&&field_name) {
+                    let err = SemanticError {
+                        message: format!("Reserved field name in {} '{}': {}", packet.kind, packet.name, field_name),
                     };
                     all_errors.push(Box::new(err));
                 }
 
                 existing_names.push(field_name);
 
+                self.analyze_field_type(context, owner.as_str(), field, &field.typ, &mut all_errors);
+
+            }
+
+        }
+
+        for union in &schema.unions {
+
+            let owner = format!("union '{}'", union.name);
+
+            for variant in &union.variants {
+                self.analyze_field_type(context, owner.as_str(), variant, &variant.typ, &mut all_errors);
             }
 
         }
@@ -41,4 +133,134 @@ impl SemanticAnalyzer for FieldSemanticAnalyzer {
 
     }
 
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::test_utils::{error_messages, parse_schema};
+
+    #[test]
+    fn rejects_reserved_field_name() {
+
+        let schema = parse_schema(r#"
+            packet ChatAck {
+                id = 1
+                reserved "read";
+                fields {
+                    bool delivered
+                    bool read
+                }
+            }
+        "#);
+
+        let errors = error_messages(FieldSemanticAnalyzer.analyze(&schema, &schema));
+
+        assert_eq!(errors, vec!["SemanticError: Reserved field name in packet 'ChatAck': read"]);
+    }
+
+
+    #[test]
+    fn rejects_optional_twice() {
+
+        let schema = parse_schema(r#"
+            packet Login {
+                id = 1
+                fields {
+                    optional string nick
+                    optional optional string name
+                    array optional optional i32 scores
+                }
+            }
+        "#);
+
+        assert_eq!(error_messages(FieldSemanticAnalyzer.analyze(&schema, &schema)), vec![
+            "SemanticError: Field 'name' in packet 'Login' can not be optional twice",
+            "SemanticError: Field 'scores' in packet 'Login' can not be optional twice",
+        ]);
+    }
+
+    #[test]
+    fn rejects_non_primitive_map_keys() {
+
+        let schema = parse_schema(r#"
+            struct Position {
+                fields {
+                    f32 x
+                }
+            }
+            packet Scores {
+                id = 1
+                fields {
+                    map string i32 by_name
+                    map u8 Position by_slot
+                    map Position i32 by_position
+                    map string map Position i32 nested
+                }
+            }
+        "#);
+
+        assert_eq!(error_messages(FieldSemanticAnalyzer.analyze(&schema, &schema)), vec![
+            "SemanticError: Map key of field 'by_position' in packet 'Scores' must be a primitive or string, actual type is Position",
+            "SemanticError: Map key of field 'nested' in packet 'Scores' must be a primitive or string, actual type is Position",
+        ]);
+    }
+
+    #[test]
+    fn rejects_set_elements_other_than_primitive_string_or_enum() {
+
+        let schema = parse_schema(r#"
+            enum Color : u8 { RED, GREEN }
+            struct Position {
+                fields {
+                    f32 x
+                }
+            }
+            packet Team {
+                id = 1
+                fields {
+                    set i32 ids
+                    set string names
+                    set Color colors
+                    set Position positions
+                    set array i32 groups
+                }
+            }
+        "#);
+
+        assert_eq!(error_messages(FieldSemanticAnalyzer.analyze(&schema, &schema)), vec![
+            "SemanticError: Set element of field 'positions' in packet 'Team' must be a primitive, string or enum, actual type is Position",
+            "SemanticError: Set element of field 'groups' in packet 'Team' must be a primitive, string or enum, actual type is array[i32]",
+        ]);
+    }
+
+    #[test]
+    fn ignores_errors_of_imported_packets() {
+
+        let schema = parse_schema(r#"
+            packet Login {
+                id = 1
+                fields {
+                    Shared shared
+                }
+            }
+        "#);
+
+        let mut context = schema.clone();
+        context.merge(&parse_schema(r#"
+            struct Shared {
+                fields {
+                    i32 a
+                    i32 a
+                }
+            }
+        "#));
+
+        assert!(error_messages(FieldSemanticAnalyzer.analyze(&schema, &context)).is_empty());
+        assert_eq!(error_messages(FieldSemanticAnalyzer.analyze(&context, &context)), vec![
+            "SemanticError: Duplicate field name in struct 'Shared': a",
+        ]);
+    }
+
+}
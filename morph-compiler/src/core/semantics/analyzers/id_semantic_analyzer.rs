@@ -1,5 +1,5 @@
-use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
-use crate::core::{Packet, SemanticError};
+use crate::core::semantics::semantic_analyzer::{imported, SemanticAnalyzer};
+use crate::core::{Direction, Packet, PacketKind, Schema, SemanticError};
 use crate::utils::MorphResult::{Errors, Success};
 use crate::utils::{MorphError, MorphResult};
 
@@ -7,14 +7,34 @@ pub struct IdSemanticAnalyzer;
 
 impl SemanticAnalyzer for IdSemanticAnalyzer {
 
-    fn analyze(&self, packets: &Vec<Packet>) -> MorphResult<()> {
+    fn analyze(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
 
         let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
-        let mut existing_ids: Vec<i32> = Vec::new();
+        let mut existing_ids: Vec<(i32, Option<Direction>, Option<&String>)> = imported(&context.packets, &schema.packets, Packet::qualified_name)
+            .into_iter()
+            .filter(|packet| !packet.is_auto && packet.kind == PacketKind::Packet)
+            .map(|packet| (packet.id, packet.direction, packet.state.as_ref()))
+            .collect();
 
-        for packet in packets {
+        for range in &schema.reserved.ids {
+            if *range.start() < 0 || *range.end() > 255 {
+                let err = SemanticError {
+                    message: format!("Reserved ids must be between 0 and 255, actual range is {}..{}", range.start(), range.end()),
+                };
+                all_errors.push(Box::new(err));
+            }
+        }
+
+        for packet in &schema.packets {
             
-            if packet.is_auto {
+            if packet.is_auto && context.reserved.auto_id(packet.qualified_name().as_str()).is_none() {
+                let err = SemanticError {
+                    message: format!("Auto id of packet '{}' can not be assigned, all ids from 0 to 127 are reserved", packet.name),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+            if packet.is_auto || packet.kind == PacketKind::Struct {
                 continue
             }
             
@@ -27,15 +47,39 @@ impl SemanticAnalyzer for IdSemanticAnalyzer {
                 };
                 all_errors.push(Box::new(err));
             }
+
+            if packet_id > 255 {
+                let err = SemanticError {
+                    message: format!("Id of packet '{}' can not be greater than 255, actual value is {}", packet_name, packet_id),
+                };
+                all_errors.push(Box::new(err));
+            }
             
-            if existing_ids.contains(&packet_id) {
+            if context.reserved.is_id_reserved(packet_id) {
                 let err = SemanticError {
-                    message: format!("Duplicate packet id: {}", packet_id),
+                    message: format!("Id of packet '{}' is reserved: {}", packet_name, packet_id),
                 };
                 all_errors.push(Box::new(err));
             }
+
+            let state = packet.state.as_ref();
+
+            let is_duplicate = existing_ids.iter().any(|(id, direction, existing_state)| {
+                *id == packet_id
+                    && (direction.is_none() || packet.direction.is_none() || *direction == packet.direction)
+                    && *existing_state == state
+            });
+
+            if is_duplicate {
+                let direction = packet.direction.map(|direction| format!("{} ", direction)).unwrap_or_default();
+                let message = match state {
+                    Some(state) => format!("Duplicate {}packet id in state '{}': {}", direction, state, packet_id),
+                    None => format!("Duplicate {}packet id: {}", direction, packet_id),
+                };
+                all_errors.push(Box::new(SemanticError { message }));
+            }
             
-            existing_ids.push(packet_id);
+            existing_ids.push((packet_id, packet.direction, state));
         }
 
         if all_errors.is_empty() {
@@ -48,4 +92,76 @@ impl SemanticAnalyzer for IdSemanticAnalyzer {
 
     }
 
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::test_utils::{error_messages, parse_schema};
+
+    #[test]
+    fn rejects_packet_id_in_reserved_range() {
+
+        let schema = parse_schema(r#"
+            reserved 12, 15..20;
+            packet Login {
+                id = 17
+                fields {
+                    string name
+                }
+            }
+            packet Logout {
+                id = 21
+                fields {
+                    string reason
+                }
+            }
+        "#);
+
+        let errors = error_messages(IdSemanticAnalyzer.analyze(&schema, &schema));
+
+        assert_eq!(errors, vec!["SemanticError: Id of packet 'Login' is reserved: 17"]);
+    }
+
+
+    #[test]
+    fn reports_duplicate_ids_only_for_own_packets() {
+
+        let schema = parse_schema(r#"
+            packet Login {
+                id = 1
+                fields {
+                    string name
+                }
+            }
+        "#);
+
+        let mut context = schema.clone();
+        context.merge(&parse_schema(r#"
+            packet Join {
+                id = 1
+                fields {
+                    string name
+                }
+            }
+            packet Kick {
+                id = 2
+                fields {
+                    string reason
+                }
+            }
+            packet Ban {
+                id = 2
+                fields {
+                    string reason
+                }
+            }
+        "#));
+
+        let errors = error_messages(IdSemanticAnalyzer.analyze(&schema, &context));
+
+        assert_eq!(errors, vec!["SemanticError: Duplicate packet id: 1"]);
+    }
+
+}
@@ -0,0 +1,56 @@
+use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
+use crate::core::{Packet, Schema, SemanticError};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{capitalize, MorphError, MorphResult};
+
+pub struct InheritanceSemanticAnalyzer;
+
+impl InheritanceSemanticAnalyzer {
+
+    fn analyze_parent(&self, context: &Schema, packet: &Packet, parent: &str, all_errors: &mut Vec<Box<dyn MorphError>>) {
+
+        let Some(parent_packet) = context.find_packet(parent) else {
+            let err = SemanticError {
+                message: format!("Parent '{}' of {} '{}' does not exist", parent, packet.kind, packet.name),
+            };
+            all_errors.push(Box::new(err));
+            return;
+        };
+
+        if parent_packet.kind != packet.kind {
+            let err = SemanticError {
+                message: format!(
+                    "{} '{}' can not extend {} '{}'",
+                    capitalize(packet.kind.to_string().as_str()), packet.name, parent_packet.kind, parent_packet.name
+                ),
+            };
+            all_errors.push(Box::new(err));
+        }
+
+    }
+
+}
+
+impl SemanticAnalyzer for InheritanceSemanticAnalyzer {
+
+    fn analyze(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        for packet in &schema.packets {
+            if let Some(parent) = &packet.parent {
+                self.analyze_parent(context, packet, parent, &mut all_errors);
+            }
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
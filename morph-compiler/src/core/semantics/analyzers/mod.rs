@@ -3,4 +3,13 @@ pub mod composite_semantic_analyzer;
 pub mod name_semantic_analyzer;
 pub mod id_semantic_analyzer;
 pub mod field_semantic_analyzer;
-
+pub mod enum_semantic_analyzer;
+pub mod union_semantic_analyzer;
+pub mod default_semantic_analyzer;
+pub mod constant_semantic_analyzer;
+pub mod constraint_semantic_analyzer;
+pub mod attribute_semantic_analyzer;
+pub mod inheritance_semantic_analyzer;
+pub mod state_semantic_analyzer;
+pub mod service_semantic_analyzer;
+pub mod version_semantic_analyzer;
@@ -1,28 +1,127 @@
-use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
-use crate::core::{Packet, SemanticError};
+use crate::core::semantics::semantic_analyzer::{imported, SemanticAnalyzer};
+use crate::core::{Schema, SemanticError};
 use crate::utils::MorphResult::{Errors, Success};
 use crate::utils::{MorphError, MorphResult};
 
 pub struct NameSemanticAnalyzer;
 
+impl NameSemanticAnalyzer {
+
+    fn analyze_reserved_name(&self, context: &Schema, name: &str, description: &str, all_errors: &mut Vec<Box<dyn MorphError>>) {
+        if context.reserved.is_name_reserved(name) {
+            let err = SemanticError {
+                message: format!("Reserved {} name: {}", description, name),
+            };
+            all_errors.push(Box::new(err));
+        }
+    }
+
+    fn declaration_names(&self, schema: &Schema) -> Vec<String> {
+        schema.packets.iter().chain(schema.templates.iter()).map(|packet| packet.qualified_name())
+            .chain(schema.enums.iter().map(|enum_decl| enum_decl.qualified_name()))
+            .chain(schema.unions.iter().map(|union| union.qualified_name()))
+            .chain(schema.aliases.iter().map(|alias| alias.qualified_name()))
+            .chain(schema.services.iter().map(|service| service.qualified_name()))
+            .collect()
+    }
+
+    fn analyze_simple_name(&self, name: &str, description: &str, all_errors: &mut Vec<Box<dyn MorphError>>) {
+        if name.contains('.') {
+            let err = SemanticError {
+                message: format!("Name of {} '{}' can not contain '.', use a namespace instead", description, name),
+            };
+            all_errors.push(Box::new(err));
+        }
+    }
+
+}
+
 impl SemanticAnalyzer for NameSemanticAnalyzer {
 
-    fn analyze(&self, packets: &Vec<Packet>) -> MorphResult<()> {
+    fn analyze(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
 
         let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
-        let mut existing_names: Vec<String> = Vec::new();
+        let mut existing_names: Vec<String> = imported(&self.declaration_names(context), &self.declaration_names(schema), String::clone)
+            .into_iter()
+            .cloned()
+            .collect();
 
-        for packet in packets {
-            let packet_name = packet.name.to_string();
+        for packet in schema.packets.iter().chain(schema.templates.iter()) {
+            self.analyze_simple_name(packet.name.as_str(), packet.kind.to_string().as_str(), &mut all_errors);
+            let packet_name = packet.qualified_name();
+            self.analyze_reserved_name(context, packet_name.as_str(), packet.kind.to_string().as_str(), &mut all_errors);
             if existing_names.contains(&packet_name) {
                 let err = SemanticError {
-                    message: format!("Duplicate packet name: {}", packet_name),
+                    message: format!("Duplicate {} name: {}", packet.kind, packet_name),
                 };
                 all_errors.push(Box::new(err));
             }
             existing_names.push(packet_name);
         }
 
+        for template in &schema.templates {
+            for (index, param) in template.type_params.iter().enumerate() {
+                if template.type_params[..index].contains(param) {
+                    let err = SemanticError {
+                        message: format!("Duplicate type parameter '{}' in generic struct '{}'", param, template.qualified_name()),
+                    };
+                    all_errors.push(Box::new(err));
+                }
+            }
+        }
+
+        for enum_decl in &schema.enums {
+            self.analyze_simple_name(enum_decl.name.as_str(), "enum", &mut all_errors);
+            let enum_name = enum_decl.qualified_name();
+            self.analyze_reserved_name(context, enum_name.as_str(), "enum", &mut all_errors);
+            if existing_names.contains(&enum_name) {
+                let err = SemanticError {
+                    message: format!("Duplicate enum name: {}", enum_name),
+                };
+                all_errors.push(Box::new(err));
+            }
+            existing_names.push(enum_name);
+        }
+
+        for union in &schema.unions {
+            self.analyze_simple_name(union.name.as_str(), "union", &mut all_errors);
+            let union_name = union.qualified_name();
+            self.analyze_reserved_name(context, union_name.as_str(), "union", &mut all_errors);
+            if existing_names.contains(&union_name) {
+                let err = SemanticError {
+                    message: format!("Duplicate union name: {}", union_name),
+                };
+                all_errors.push(Box::new(err));
+            }
+            existing_names.push(union_name);
+        }
+
+        for alias in &schema.aliases {
+            self.analyze_simple_name(alias.name.as_str(), "type alias", &mut all_errors);
+            let alias_name = alias.qualified_name();
+            self.analyze_reserved_name(context, alias_name.as_str(), "type alias", &mut all_errors);
+            if existing_names.contains(&alias_name) {
+                let err = SemanticError {
+                    message: format!("Duplicate type alias name: {}", alias_name),
+                };
+                all_errors.push(Box::new(err));
+            }
+            existing_names.push(alias_name);
+        }
+
+        for service in &schema.services {
+            self.analyze_simple_name(service.name.as_str(), "service", &mut all_errors);
+            let service_name = service.qualified_name();
+            self.analyze_reserved_name(context, service_name.as_str(), "service", &mut all_errors);
+            if existing_names.contains(&service_name) {
+                let err = SemanticError {
+                    message: format!("Duplicate service name: {}", service_name),
+                };
+                all_errors.push(Box::new(err));
+            }
+            existing_names.push(service_name);
+        }
+
         if all_errors.is_empty() {
             Success(())
 
@@ -33,4 +132,30 @@ impl SemanticAnalyzer for NameSemanticAnalyzer {
 
     }
 
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::test_utils::{error_messages, parse_schema};
+
+    #[test]
+    fn rejects_reserved_declaration_name() {
+
+        let schema = parse_schema(r#"
+            reserved "LegacyLogin";
+            packet LegacyLogin {
+                id = 1
+                fields {
+                    string name
+                }
+            }
+        "#);
+
+        let errors = error_messages(NameSemanticAnalyzer.analyze(&schema, &schema));
+
+        assert_eq!(errors, vec!["SemanticError: Reserved packet name: LegacyLogin"]);
+    }
+
+}
@@ -0,0 +1,87 @@
+use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
+use crate::core::{Rpc, Schema, SemanticError, Service};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{MorphError, MorphResult};
+
+pub struct ServiceSemanticAnalyzer;
+
+impl ServiceSemanticAnalyzer {
+
+    fn analyze_service(&self, context: &Schema, service: &Service, all_errors: &mut Vec<Box<dyn MorphError>>) {
+
+        if service.methods.is_empty() {
+            let err = SemanticError {
+                message: format!("Service '{}' must declare at least one rpc", service.name),
+            };
+            all_errors.push(Box::new(err));
+        }
+
+        if service.methods.len() > 256 {
+            let err = SemanticError {
+                message: format!("Service '{}' can not have more than 256 rpcs, actual count is {}", service.name, service.methods.len()),
+            };
+            all_errors.push(Box::new(err));
+        }
+
+        let mut existing_names: Vec<&String> = Vec::new();
+
+        for rpc in &service.methods {
+
+            if existing_names.contains(&&rpc.name) {
+                let err = SemanticError {
+                    message: format!("Duplicate rpc name in service '{}': {}", service.name, rpc.name),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+            existing_names.push(&rpc.name);
+
+            self.analyze_message_type(context, service, rpc, "Request", &rpc.request, all_errors);
+            self.analyze_message_type(context, service, rpc, "Response", &rpc.response, all_errors);
+        }
+
+    }
+
+    fn analyze_message_type(
+        &self,
+        context: &Schema,
+        service: &Service,
+        rpc: &Rpc,
+        description: &str,
+        type_name: &str,
+        all_errors: &mut Vec<Box<dyn MorphError>>,
+    ) {
+        if context.find_packet(type_name).is_none() {
+            let err = SemanticError {
+                message: format!(
+                    "{} type '{}' of rpc '{}' in service '{}' must be a packet or struct",
+                    description, type_name, rpc.name, service.name
+                ),
+            };
+            all_errors.push(Box::new(err));
+        }
+    }
+
+}
+
+impl SemanticAnalyzer for ServiceSemanticAnalyzer {
+
+    fn analyze(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        for service in &schema.services {
+            self.analyze_service(context, service, &mut all_errors);
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
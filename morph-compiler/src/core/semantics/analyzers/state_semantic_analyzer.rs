@@ -0,0 +1,83 @@
+use crate::core::semantics::semantic_analyzer::{imported, SemanticAnalyzer};
+use crate::core::{PacketKind, Schema, SemanticError, Transition};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{MorphError, MorphResult};
+
+pub struct StateSemanticAnalyzer;
+
+impl StateSemanticAnalyzer {
+
+    fn analyze_transition(&self, context: &Schema, transition: &Transition, all_errors: &mut Vec<Box<dyn MorphError>>) {
+
+        for state in [&transition.from, &transition.to] {
+            if !context.states.contains(state) {
+                let err = SemanticError {
+                    message: format!("State '{}' of transition '{}' does not exist", state, transition),
+                };
+                all_errors.push(Box::new(err));
+            }
+        }
+
+        let Some(packet) = context.find_packet(&transition.packet) else {
+            let err = SemanticError {
+                message: format!("Packet '{}' of transition '{}' does not exist", transition.packet, transition),
+            };
+            all_errors.push(Box::new(err));
+            return;
+        };
+
+        if packet.kind != PacketKind::Packet {
+            let err = SemanticError {
+                message: format!("Transition '{}' can only be triggered by a packet, but '{}' is a {}", transition, packet.name, packet.kind),
+            };
+            all_errors.push(Box::new(err));
+
+        } else if packet.state.as_ref() != Some(&transition.from) {
+            let err = SemanticError {
+                message: format!("Packet '{}' of transition '{}' does not belong to state '{}'", packet.name, transition, transition.from),
+            };
+            all_errors.push(Box::new(err));
+
+        }
+
+    }
+
+}
+
+impl SemanticAnalyzer for StateSemanticAnalyzer {
+
+    fn analyze(&self, schema: &Schema, context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+        let mut existing_triggers: Vec<(&String, &String)> = imported(&context.transitions, &schema.transitions, Transition::to_string)
+            .into_iter()
+            .map(|transition| (&transition.from, &transition.packet))
+            .collect();
+
+        for transition in &schema.transitions {
+
+            self.analyze_transition(context, transition, &mut all_errors);
+
+            let trigger = (&transition.from, &transition.packet);
+
+            if existing_triggers.contains(&trigger) {
+                let err = SemanticError {
+                    message: format!("Duplicate transition from state '{}' on packet '{}'", transition.from, transition.packet),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+            existing_triggers.push(trigger);
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
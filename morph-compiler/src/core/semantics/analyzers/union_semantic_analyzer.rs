@@ -0,0 +1,74 @@
+use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
+use crate::core::{Schema, SemanticError, Union};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{MorphError, MorphResult};
+
+pub struct UnionSemanticAnalyzer;
+
+impl UnionSemanticAnalyzer {
+
+    fn analyze_union(&self, union: &Union, all_errors: &mut Vec<Box<dyn MorphError>>) {
+
+        if union.variants.is_empty() {
+            let err = SemanticError {
+                message: format!("Union '{}' must declare at least one variant", union.name),
+            };
+            all_errors.push(Box::new(err));
+        }
+
+        if union.variants.len() > u8::MAX as usize + 1 {
+            let err = SemanticError {
+                message: format!(
+                    "Union '{}' can not declare more than {} variants, actual count is {}",
+                    union.name, u8::MAX as usize + 1, union.variants.len()
+                ),
+            };
+            all_errors.push(Box::new(err));
+        }
+
+        let mut existing_names: Vec<String> = Vec::new();
+
+        for variant in &union.variants {
+
+            if existing_names.contains(&variant.name) {
+                let err = SemanticError {
+                    message: format!("Duplicate variant name in union '{}': {}", union.name, variant.name),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+            if variant.default.is_some() {
+                let err = SemanticError {
+                    message: format!("Variant '{}' in union '{}' can not have a default value", variant.name, union.name),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+            existing_names.push(variant.name.to_string());
+        }
+
+    }
+
+}
+
+impl SemanticAnalyzer for UnionSemanticAnalyzer {
+
+    fn analyze(&self, schema: &Schema, _context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        for union in &schema.unions {
+            self.analyze_union(union, &mut all_errors);
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
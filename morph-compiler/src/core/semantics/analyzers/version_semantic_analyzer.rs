@@ -0,0 +1,200 @@
+use crate::core::semantics::semantic_analyzer::SemanticAnalyzer;
+use crate::core::{find_attribute, Attribute, Literal, Schema, SemanticError};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{MorphError, MorphResult};
+
+pub struct VersionSemanticAnalyzer;
+
+fn version_attribute(attributes: &[Attribute], name: &str) -> Option<i64> {
+    match find_attribute(attributes, name).and_then(|attribute| attribute.positional_arg(0)) {
+        Some(Literal::Integer(version)) => Some(*version),
+        _ => None,
+    }
+}
+
+impl VersionSemanticAnalyzer {
+
+    fn analyze_attributes(
+        &self,
+        target: &str,
+        attributes: &[Attribute],
+        version: Option<u32>,
+        is_field: bool,
+        is_variant: bool,
+        all_errors: &mut Vec<Box<dyn MorphError>>
+    ) {
+
+        let since = version_attribute(attributes, "since");
+        let until = version_attribute(attributes, "until");
+
+        if is_variant {
+            for (name, value) in [("since", since), ("until", until)] {
+                if value.is_some() {
+                    let err = SemanticError {
+                        message: format!("Attribute '@{}' on {} is not allowed on union variants", name, target),
+                    };
+                    all_errors.push(Box::new(err));
+                }
+            }
+            return;
+        }
+
+        if let Some(until) = until {
+
+            if !is_field {
+                let err = SemanticError {
+                    message: format!("Attribute '@until' on {} is only allowed on fields", target),
+                };
+                all_errors.push(Box::new(err));
+                return;
+            }
+
+            if version.is_none() {
+                let err = SemanticError {
+                    message: format!("Attribute '@until' on {} requires a declared schema version", target),
+                };
+                all_errors.push(Box::new(err));
+                return;
+            }
+
+            if until <= since.unwrap_or(0) {
+                let err = SemanticError {
+                    message: format!(
+                        "No version is valid for {}, '@since({})' must be lower than '@until({})'",
+                        target, since.unwrap_or(0), until
+                    ),
+                };
+                all_errors.push(Box::new(err));
+            }
+
+        }
+
+        let Some(version) = version else {
+            if since.is_some() {
+                let err = SemanticError {
+                    message: format!("Attribute '@since' on {} requires a declared schema version", target),
+                };
+                all_errors.push(Box::new(err));
+            }
+            return;
+        };
+
+        for (name, value) in [("since", since), ("until", until)] {
+            if let Some(value) = value.filter(|value| *value > version as i64) {
+                let err = SemanticError {
+                    message: format!(
+                        "Attribute '@{}' on {} uses version {}, which is higher than the current version {}",
+                        name, target, value, version
+                    ),
+                };
+                all_errors.push(Box::new(err));
+            }
+        }
+
+    }
+
+}
+
+impl SemanticAnalyzer for VersionSemanticAnalyzer {
+
+    fn analyze(&self, schema: &Schema, _context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        for packet in &schema.packets {
+
+            let target = format!("{} '{}'", packet.kind, packet.name);
+            self.analyze_attributes(target.as_str(), &packet.attributes, packet.version, false, false, &mut all_errors);
+
+            for field in &packet.fields {
+                let target = format!("field '{}' in {} '{}'", field.name, packet.kind, packet.name);
+                self.analyze_attributes(target.as_str(), &field.attributes, packet.version, true, false, &mut all_errors);
+            }
+
+        }
+
+        for union in &schema.unions {
+            for variant in &union.variants {
+                let target = format!("variant '{}' in union '{}'", variant.name, union.name);
+                self.analyze_attributes(target.as_str(), &variant.attributes, union.version, false, true, &mut all_errors);
+            }
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::test_utils::{error_messages, parse_schema};
+
+    fn analyze(source: &str) -> Vec<String> {
+        let schema = parse_schema(source);
+        error_messages(VersionSemanticAnalyzer.analyze(&schema, &schema))
+    }
+
+    #[test]
+    fn rejects_since_without_schema_version() {
+
+        let errors = analyze(r#"
+            @since(1)
+            packet Login {
+                id = 1
+                fields {
+                    @since(2)
+                    string name
+                }
+            }
+        "#);
+
+        assert_eq!(errors, vec![
+            "SemanticError: Attribute '@since' on packet 'Login' requires a declared schema version",
+            "SemanticError: Attribute '@since' on field 'name' in packet 'Login' requires a declared schema version",
+        ]);
+    }
+
+    #[test]
+    fn rejects_since_on_union_variant() {
+
+        let errors = analyze(r#"
+            version = 2;
+            union Shape {
+                @since(2)
+                f32 circle
+                i32 square
+            }
+        "#);
+
+        assert_eq!(errors, vec![
+            "SemanticError: Attribute '@since' on variant 'circle' in union 'Shape' is not allowed on union variants",
+        ]);
+    }
+
+    #[test]
+    fn rejects_until_on_union_variant() {
+
+        let errors = analyze(r#"
+            version = 2;
+            union Shape {
+                f32 circle
+                @until(2)
+                i32 square
+            }
+        "#);
+
+        assert_eq!(errors, vec![
+            "SemanticError: Attribute '@until' on variant 'square' in union 'Shape' is not allowed on union variants",
+        ]);
+    }
+
+}
@@ -1,7 +1,10 @@
 mod semantic_analyzer;
+mod semantic_resolver;
 mod analyzers;
+mod resolvers;
 
 pub use semantic_analyzer::*;
+pub use semantic_resolver::*;
 pub use crate::utils::dependency_resolver::*;
 
 pub use analyzers::composite_semantic_analyzer::*;
@@ -9,4 +12,19 @@ pub use analyzers::id_semantic_analyzer::*;
 pub use analyzers::name_semantic_analyzer::*;
 pub use analyzers::dependency_semantic_analyzer::*;
 pub use analyzers::field_semantic_analyzer::*;
+pub use analyzers::enum_semantic_analyzer::*;
+pub use analyzers::union_semantic_analyzer::*;
+pub use analyzers::default_semantic_analyzer::*;
+pub use analyzers::constant_semantic_analyzer::*;
+pub use analyzers::constraint_semantic_analyzer::*;
+pub use analyzers::attribute_semantic_analyzer::*;
+pub use analyzers::inheritance_semantic_analyzer::*;
+pub use analyzers::state_semantic_analyzer::*;
+pub use analyzers::service_semantic_analyzer::*;
+pub use analyzers::version_semantic_analyzer::*;
 
+pub use resolvers::composite_semantic_resolver::*;
+pub use resolvers::namespace_semantic_resolver::*;
+pub use resolvers::constant_semantic_resolver::*;
+pub use resolvers::alias_semantic_resolver::*;
+pub use resolvers::generic_semantic_resolver::*;
@@ -0,0 +1,198 @@
+use crate::core::semantics::semantic_resolver::SemanticResolver;
+use crate::core::FieldType::{Array, FixedArray, Generic, Map, Nested, Optional, Set};
+use crate::core::{FieldType, Schema, SemanticError, TypeAlias};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{MorphError, MorphResult};
+use std::collections::{HashMap, HashSet};
+
+pub struct AliasSemanticResolver;
+
+impl AliasSemanticResolver {
+
+    fn expand_type(
+        &self,
+        aliases: &HashMap<String, &TypeAlias>,
+        field_type: &FieldType,
+        visited: &mut Vec<String>,
+    ) -> Result<FieldType, String> {
+        match field_type {
+            Nested(type_name) => {
+
+                let Some(alias) = aliases.get(type_name) else {
+                    return Ok(field_type.clone());
+                };
+
+                if visited.contains(type_name) {
+                    visited.push(type_name.clone());
+                    return Err(format!("Cycle detected in type aliases: {}", visited.join(" -> ")));
+                }
+
+                visited.push(type_name.clone());
+                let expanded = self.expand_type(aliases, &alias.typ, visited);
+                visited.pop();
+
+                expanded
+            }
+            Array(inner_type) => {
+                Ok(Array(Box::new(self.expand_type(aliases, inner_type, visited)?)))
+            }
+            FixedArray(inner_type, size) => {
+                Ok(FixedArray(Box::new(self.expand_type(aliases, inner_type, visited)?), size.clone()))
+            }
+            Set(inner_type) => {
+                Ok(Set(Box::new(self.expand_type(aliases, inner_type, visited)?)))
+            }
+            Optional(inner_type) => {
+                Ok(Optional(Box::new(self.expand_type(aliases, inner_type, visited)?)))
+            }
+            Generic(type_name, args) => {
+                let args = args.iter()
+                    .map(|arg| self.expand_type(aliases, arg, visited))
+                    .collect::<Result<Vec<FieldType>, String>>()?;
+                Ok(Generic(type_name.clone(), args))
+            }
+            Map(key_type, value_type) => {
+                Ok(Map(
+                    Box::new(self.expand_type(aliases, key_type, visited)?),
+                    Box::new(self.expand_type(aliases, value_type, visited)?)
+                ))
+            }
+            _ => Ok(field_type.clone())
+        }
+    }
+
+    fn find_unknown_type<'a>(&self, known_types: &HashSet<String>, field_type: &'a FieldType) -> Option<&'a str> {
+        match field_type {
+            Nested(type_name) if !known_types.contains(type_name) => Some(type_name),
+            Array(inner_type) | FixedArray(inner_type, _) | Set(inner_type) | Optional(inner_type) => {
+                self.find_unknown_type(known_types, inner_type)
+            }
+            Generic(_, args) => args.iter().find_map(|arg| self.find_unknown_type(known_types, arg)),
+            Map(key_type, value_type) => {
+                self.find_unknown_type(known_types, key_type)
+                    .or_else(|| self.find_unknown_type(known_types, value_type))
+            }
+            _ => None
+        }
+    }
+
+}
+
+impl SemanticResolver for AliasSemanticResolver {
+
+    fn resolve(&self, schema: &mut Schema, context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        let aliases: HashMap<String, &TypeAlias> = context.aliases.iter()
+            .map(|alias| (alias.qualified_name(), alias))
+            .collect();
+
+        let known_types: HashSet<String> = context.packets.iter().map(|packet| packet.qualified_name())
+            .chain(context.enums.iter().map(|enum_decl| enum_decl.qualified_name()))
+            .chain(context.unions.iter().map(|union| union.qualified_name()))
+            .collect();
+
+        for alias in &mut schema.aliases {
+            match self.expand_type(&aliases, &alias.typ, &mut vec![alias.qualified_name()]) {
+                Ok(expanded) => {
+                    if let Some(type_name) = self.find_unknown_type(&known_types, &expanded) {
+                        let err = SemanticError {
+                            message: format!("Unknown type '{}' in type alias '{}'", type_name, alias.name),
+                        };
+                        all_errors.push(Box::new(err));
+                    }
+                    alias.typ = expanded;
+                }
+                Err(message) => {
+                    let err = SemanticError { message };
+                    all_errors.push(Box::new(err));
+                }
+            }
+        }
+
+        for packet in schema.packets.iter_mut().chain(schema.templates.iter_mut()) {
+            for field in &mut packet.fields {
+                if let Ok(expanded) = self.expand_type(&aliases, &field.typ, &mut Vec::new())
+                    && expanded != field.typ {
+                    field.alias_type = Some(std::mem::replace(&mut field.typ, expanded));
+                }
+            }
+        }
+
+        for union in &mut schema.unions {
+            for variant in &mut union.variants {
+                if let Ok(expanded) = self.expand_type(&aliases, &variant.typ, &mut Vec::new())
+                    && expanded != variant.typ {
+                    variant.alias_type = Some(std::mem::replace(&mut variant.typ, expanded));
+                }
+            }
+        }
+
+        for enum_decl in &mut schema.enums {
+            if let Ok(expanded) = self.expand_type(&aliases, &enum_decl.typ, &mut Vec::new()) {
+                enum_decl.typ = expanded;
+            }
+        }
+
+        for constant in &mut schema.constants {
+            if let Ok(expanded) = self.expand_type(&aliases, &constant.typ, &mut Vec::new()) {
+                constant.typ = expanded;
+            }
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::test_utils::{error_messages, parse_schema};
+
+    fn resolve(source: &str) -> Vec<String> {
+        let mut schema = parse_schema(source);
+        let context = schema.clone();
+        error_messages(AliasSemanticResolver.resolve(&mut schema, &context))
+    }
+
+    #[test]
+    fn rejects_alias_cycle() {
+
+        let errors = resolve(r#"
+            type A = B;
+            type B = A;
+        "#);
+
+        assert_eq!(errors, vec![
+            "SemanticError: Cycle detected in type aliases: A -> B -> A",
+            "SemanticError: Cycle detected in type aliases: B -> A -> B",
+        ]);
+    }
+
+    #[test]
+    fn rejects_alias_to_undefined_type() {
+
+        let errors = resolve(r#"
+            struct Position {
+                fields {
+                    f32 x
+                }
+            }
+            type Path = array Position;
+            type Target = optional Missing;
+        "#);
+
+        assert_eq!(errors, vec!["SemanticError: Unknown type 'Missing' in type alias 'Target'"]);
+    }
+
+}
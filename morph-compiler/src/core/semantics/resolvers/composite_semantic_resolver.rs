@@ -0,0 +1,49 @@
+use crate::core::semantics::semantic_resolver::SemanticResolver;
+use crate::core::Schema;
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::MorphResult;
+
+pub struct CompositeSemanticResolver {
+    resolvers: Vec<Box<dyn SemanticResolver>>,
+}
+
+impl CompositeSemanticResolver {
+
+    pub fn new() -> CompositeSemanticResolver {
+        CompositeSemanticResolver { resolvers: vec![] }
+    }
+
+    pub fn add_resolver(&mut self, resolver: Box<dyn SemanticResolver>) {
+        self.resolvers.push(resolver);
+    }
+
+    pub fn resolve_context(&self, context: &mut Schema) -> MorphResult<()> {
+
+        for resolver in &self.resolvers {
+            let declarations = context.clone();
+            if let Errors(errors) = resolver.resolve(context, &declarations) {
+                return Errors(errors);
+            }
+        }
+
+        Success(())
+
+    }
+
+}
+
+impl SemanticResolver for CompositeSemanticResolver {
+
+    fn resolve(&self, schema: &mut Schema, context: &Schema) -> MorphResult<()> {
+
+        for resolver in &self.resolvers {
+            if let Errors(errors) = resolver.resolve(schema, context) {
+                return Errors(errors);
+            }
+        }
+
+        Success(())
+
+    }
+
+}
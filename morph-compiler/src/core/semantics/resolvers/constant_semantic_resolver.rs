@@ -0,0 +1,289 @@
+use crate::core::semantics::semantic_resolver::SemanticResolver;
+use crate::core::{resolve_qualified_name, ArraySize, Constant, Field, FieldType, Literal, Schema, SemanticError};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{MorphError, MorphResult};
+use std::collections::{HashMap, HashSet};
+
+pub struct ConstantSemanticResolver;
+
+struct ConstantScope<'a> {
+    constants: HashMap<String, &'a Constant>,
+    declared: HashSet<String>,
+}
+
+impl ConstantSemanticResolver {
+
+    fn resolve_value(
+        &self,
+        scope: &ConstantScope,
+        name: &str,
+        namespace: &Option<String>,
+        visited: &mut Vec<String>,
+    ) -> Result<Literal, String> {
+
+        let Some(qualified_name) = resolve_qualified_name(name, namespace, &scope.declared) else {
+            return Err(format!("Unknown constant '{}'", name));
+        };
+
+        if visited.contains(&qualified_name) {
+            visited.push(qualified_name);
+            return Err(format!("Cycle detected in constants: {}", visited.join(" -> ")));
+        }
+
+        let constant = scope.constants[&qualified_name];
+        visited.push(qualified_name);
+
+        match &constant.value {
+            Literal::Reference(reference) => self.resolve_value(scope, reference, &constant.namespace, visited),
+            value => Ok(value.clone()),
+        }
+    }
+
+    fn resolve_constraints(
+        &self,
+        scope: &ConstantScope,
+        fields: &mut [Field],
+        namespace: &Option<String>,
+        owner: &str,
+        all_errors: &mut Vec<Box<dyn MorphError>>,
+    ) {
+
+        for field in fields {
+            for constraint in &mut field.constraints {
+
+                let Literal::Reference(name) = &constraint.value else {
+                    continue;
+                };
+
+                match self.resolve_value(scope, name, namespace, &mut Vec::new()) {
+                    Ok(value) => constraint.value = value,
+                    Err(message) => {
+                        let err = SemanticError {
+                            message: format!(
+                                "{} in constraint '{}' of field '{}' in {}",
+                                message, constraint.kind, field.name, owner
+                            ),
+                        };
+                        all_errors.push(Box::new(err));
+                    }
+                }
+
+            }
+        }
+
+    }
+
+    fn resolve_sizes(
+        &self,
+        scope: &ConstantScope,
+        fields: &mut [Field],
+        namespace: &Option<String>,
+        owner: &str,
+        all_errors: &mut Vec<Box<dyn MorphError>>,
+    ) {
+
+        for field in fields {
+            if let Err(message) = self.resolve_type_size(scope, &mut field.typ, namespace) {
+                let err = SemanticError {
+                    message: format!("{} in size of field '{}' in {}", message, field.name, owner),
+                };
+                all_errors.push(Box::new(err));
+            }
+        }
+
+    }
+
+    fn resolve_type_size(&self, scope: &ConstantScope, field_type: &mut FieldType, namespace: &Option<String>) -> Result<(), String> {
+        match field_type {
+            FieldType::FixedArray(inner_type, size) => {
+
+                if let ArraySize::Reference(name) = size {
+                    match self.resolve_value(scope, name, namespace, &mut Vec::new())? {
+                        Literal::Integer(value) if value > 0 && u32::try_from(value).is_ok() => {
+                            *size = ArraySize::Value(value as u32);
+                        }
+                        value => {
+                            return Err(format!("Constant '{}' must be a positive integer, actual value is {}", name, value));
+                        }
+                    }
+                }
+
+                self.resolve_type_size(scope, inner_type, namespace)
+            }
+            FieldType::Array(inner_type) | FieldType::Set(inner_type) | FieldType::Optional(inner_type) => {
+                self.resolve_type_size(scope, inner_type, namespace)
+            }
+            FieldType::Map(key_type, value_type) => {
+                self.resolve_type_size(scope, key_type, namespace)?;
+                self.resolve_type_size(scope, value_type, namespace)
+            }
+            FieldType::Generic(_, args) => {
+                args.iter_mut().try_for_each(|arg| self.resolve_type_size(scope, arg, namespace))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn is_enum_variant(&self, context: &Schema, field_type: &FieldType, name: &str) -> bool {
+        match field_type {
+            FieldType::Optional(inner_type) => self.is_enum_variant(context, inner_type, name),
+            FieldType::Nested(type_name) => {
+                context.find_enum(type_name)
+                    .is_some_and(|enum_decl| enum_decl.variants.iter().any(|variant| variant.name == name))
+            }
+            _ => false,
+        }
+    }
+
+}
+
+impl SemanticResolver for ConstantSemanticResolver {
+
+    fn resolve(&self, schema: &mut Schema, context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+
+        let scope = ConstantScope {
+            constants: context.constants.iter()
+                .map(|constant| (constant.qualified_name(), constant))
+                .collect(),
+            declared: context.constants.iter()
+                .map(|constant| constant.qualified_name())
+                .collect(),
+        };
+
+        for constant in &mut schema.constants {
+            if let Literal::Reference(name) = &constant.value {
+                match self.resolve_value(&scope, name, &constant.namespace, &mut vec![constant.qualified_name()]) {
+                    Ok(value) => constant.value = value,
+                    Err(message) => {
+                        let err = SemanticError {
+                            message: format!("{} in value of constant '{}'", message, constant.name),
+                        };
+                        all_errors.push(Box::new(err));
+                    }
+                }
+            }
+        }
+
+        for packet in &mut schema.packets {
+
+            if let Some(name) = &packet.id_reference {
+                match self.resolve_value(&scope, name, &packet.namespace, &mut Vec::new()) {
+                    Ok(Literal::Integer(value)) if i32::try_from(value).is_ok() => packet.id = value as i32,
+                    Ok(value) => {
+                        let err = SemanticError {
+                            message: format!(
+                                "Id of {} '{}' must be an integer, actual value of constant '{}' is {}",
+                                packet.kind, packet.name, name, value
+                            ),
+                        };
+                        all_errors.push(Box::new(err));
+                    }
+                    Err(message) => {
+                        let err = SemanticError {
+                            message: format!("{} in id of {} '{}'", message, packet.kind, packet.name),
+                        };
+                        all_errors.push(Box::new(err));
+                    }
+                }
+            }
+
+            for field in &mut packet.fields {
+
+                let Some(Literal::Reference(name)) = &field.default else {
+                    continue;
+                };
+
+                if self.is_enum_variant(context, &field.typ, name) {
+                    continue;
+                }
+
+                if let Ok(value) = self.resolve_value(&scope, name, &packet.namespace, &mut Vec::new()) {
+                    field.default = Some(value);
+                }
+
+            }
+
+            let owner = format!("{} '{}'", packet.kind, packet.name);
+            self.resolve_constraints(&scope, &mut packet.fields, &packet.namespace, owner.as_str(), &mut all_errors);
+            self.resolve_sizes(&scope, &mut packet.fields, &packet.namespace, owner.as_str(), &mut all_errors);
+
+        }
+
+        for union in &mut schema.unions {
+            let owner = format!("union '{}'", union.name);
+            self.resolve_constraints(&scope, &mut union.variants, &union.namespace, owner.as_str(), &mut all_errors);
+            self.resolve_sizes(&scope, &mut union.variants, &union.namespace, owner.as_str(), &mut all_errors);
+        }
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::test_utils::{error_messages, parse_schema};
+
+    fn resolve(source: &str) -> (Schema, Vec<String>) {
+        let mut schema = parse_schema(source);
+        let context = schema.clone();
+        let errors = error_messages(ConstantSemanticResolver.resolve(&mut schema, &context));
+        (schema, errors)
+    }
+
+    #[test]
+    fn resolves_fixed_array_size_from_constant() {
+
+        let (schema, errors) = resolve(r#"
+            const HASH_LEN: u8 = 16;
+            packet Login {
+                id = 1
+                fields {
+                    array[HASH_LEN] u8 hash
+                }
+            }
+        "#);
+
+        assert!(errors.is_empty(), "{:?}", errors);
+        assert_eq!(
+            schema.packets[0].fields[0].typ,
+            FieldType::FixedArray(Box::new(FieldType::U8), ArraySize::Value(16))
+        );
+    }
+
+    #[test]
+    fn rejects_fixed_array_size_from_invalid_constants() {
+
+        let (_, errors) = resolve(r#"
+            const EMPTY: u8 = 0;
+            const NAME: string = "hash";
+            packet Login {
+                id = 1
+                fields {
+                    array[EMPTY] u8 empty
+                    array[NAME] u8 named
+                    array[MISSING] u8 missing
+                }
+            }
+        "#);
+
+        assert_eq!(errors, vec![
+            "SemanticError: Constant 'EMPTY' must be a positive integer, actual value is 0 in size of field 'empty' in packet 'Login'",
+            "SemanticError: Constant 'NAME' must be a positive integer, actual value is \"hash\" in size of field 'named' in packet 'Login'",
+            "SemanticError: Unknown constant 'MISSING' in size of field 'missing' in packet 'Login'",
+        ]);
+    }
+
+}
@@ -0,0 +1,225 @@
+use crate::core::semantics::semantic_resolver::SemanticResolver;
+use crate::core::FieldType::{Array, FixedArray, Generic, Map, Nested, Optional, Set, Union};
+use crate::core::{find_attribute, qualify_name, Field, FieldType, Packet, Schema, SemanticError};
+use crate::utils::MorphResult::{Errors, Success};
+use crate::utils::{capitalize, MorphError, MorphResult};
+use std::collections::HashMap;
+
+const MAX_SPECIALIZATION_DEPTH: usize = 16;
+
+pub struct GenericSemanticResolver;
+
+#[derive(Default)]
+struct Specializations {
+    packets: Vec<Packet>,
+    arguments: HashMap<String, Vec<FieldType>>,
+}
+
+impl GenericSemanticResolver {
+
+    fn specialize_type(
+        &self,
+        field_type: &FieldType,
+        context: &Schema,
+        specializations: &mut Specializations,
+        depth: usize,
+    ) -> Result<FieldType, String> {
+        match field_type {
+            Nested(type_name) if context.find_template(type_name).is_some() => {
+                Err(format!("Generic struct '{}' is used without type arguments", type_name))
+            }
+            Generic(type_name, args) => {
+                let args = args.iter()
+                    .map(|arg| self.specialize_type(arg, context, specializations, depth))
+                    .collect::<Result<Vec<FieldType>, String>>()?;
+                self.specialize(type_name, args, context, specializations, depth).map(Nested)
+            }
+            Array(inner_type) => {
+                Ok(Array(Box::new(self.specialize_type(inner_type, context, specializations, depth)?)))
+            }
+            FixedArray(inner_type, size) => {
+                Ok(FixedArray(Box::new(self.specialize_type(inner_type, context, specializations, depth)?), size.clone()))
+            }
+            Set(inner_type) => {
+                Ok(Set(Box::new(self.specialize_type(inner_type, context, specializations, depth)?)))
+            }
+            Optional(inner_type) => {
+                Ok(Optional(Box::new(self.specialize_type(inner_type, context, specializations, depth)?)))
+            }
+            Map(key_type, value_type) => {
+                Ok(Map(
+                    Box::new(self.specialize_type(key_type, context, specializations, depth)?),
+                    Box::new(self.specialize_type(value_type, context, specializations, depth)?)
+                ))
+            }
+            _ => Ok(field_type.clone())
+        }
+    }
+
+    fn specialize(
+        &self,
+        type_name: &str,
+        args: Vec<FieldType>,
+        context: &Schema,
+        specializations: &mut Specializations,
+        depth: usize,
+    ) -> Result<String, String> {
+
+        let Some(template) = context.find_template(type_name) else {
+            return match context.find_packet(type_name) {
+                Some(packet) => Err(format!("{} '{}' is not generic", capitalize(packet.kind.to_string().as_str()), type_name)),
+                None => Err(format!("Generic struct '{}' does not exist", type_name)),
+            };
+        };
+
+        if args.len() != template.type_params.len() {
+            return Err(format!(
+                "Generic struct '{}' expects {} type arguments, but got {}",
+                type_name, template.type_params.len(), args.len()
+            ));
+        }
+
+        if find_attribute(&template.attributes, "java").is_some() {
+            return Err(format!("Generic struct '{}' can not be renamed with '@java'", type_name));
+        }
+
+        let name = format!("{}{}", template.name, args.iter().map(type_argument_name).collect::<String>());
+        let qualified_name = qualify_name(&template.namespace, &name);
+
+        match specializations.arguments.get(&qualified_name) {
+            Some(existing) if *existing == args => return Ok(qualified_name),
+            Some(existing) => {
+                return Err(format!(
+                    "Specializations {} and {} have the same name '{}'",
+                    Generic(type_name.to_string(), existing.clone()), Generic(type_name.to_string(), args), name
+                ));
+            }
+            None => {}
+        }
+
+        if depth >= MAX_SPECIALIZATION_DEPTH {
+            return Err(format!("Specialization {} is nested too deeply", Generic(type_name.to_string(), args)));
+        }
+
+        specializations.arguments.insert(qualified_name.clone(), args.clone());
+
+        let mut fields: Vec<Field> = Vec::new();
+
+        for field in &template.fields {
+            let typ = substitute(&field.typ, &template.type_params, &args);
+            let typ = self.specialize_type(&typ, context, specializations, depth + 1)?;
+            fields.push(Field { typ, ..field.clone() });
+        }
+
+        specializations.packets.push(Packet { name, type_params: Vec::new(), fields, ..template.clone() });
+
+        Ok(qualified_name)
+
+    }
+
+    fn specialize_fields(
+        &self,
+        fields: &mut [Field],
+        owner: &str,
+        context: &Schema,
+        specializations: &mut Specializations,
+        all_errors: &mut Vec<Box<dyn MorphError>>,
+    ) {
+
+        for field in fields {
+            match self.specialize_type(&field.typ, context, specializations, 0) {
+                Ok(typ) if typ != field.typ => {
+                    let generic_type = std::mem::replace(&mut field.typ, typ);
+                    field.alias_type.get_or_insert(generic_type);
+                }
+                Ok(_) => {}
+                Err(message) => {
+                    let err = SemanticError {
+                        message: format!("{} in field '{}' of {}", message, field.name, owner),
+                    };
+                    all_errors.push(Box::new(err));
+                }
+            }
+        }
+
+    }
+
+}
+
+fn substitute(field_type: &FieldType, params: &[String], args: &[FieldType]) -> FieldType {
+    match field_type {
+        Nested(type_name) => match params.iter().position(|param| param == type_name) {
+            Some(index) => args[index].clone(),
+            None => field_type.clone(),
+        },
+        Generic(type_name, type_args) => {
+            Generic(type_name.clone(), type_args.iter().map(|arg| substitute(arg, params, args)).collect())
+        }
+        Array(inner_type) => Array(Box::new(substitute(inner_type, params, args))),
+        FixedArray(inner_type, size) => FixedArray(Box::new(substitute(inner_type, params, args)), size.clone()),
+        Set(inner_type) => Set(Box::new(substitute(inner_type, params, args))),
+        Optional(inner_type) => Optional(Box::new(substitute(inner_type, params, args))),
+        Map(key_type, value_type) => {
+            Map(Box::new(substitute(key_type, params, args)), Box::new(substitute(value_type, params, args)))
+        }
+        _ => field_type.clone()
+    }
+}
+
+fn type_argument_name(field_type: &FieldType) -> String {
+    match field_type {
+        Nested(type_name) | Union(type_name) => {
+            capitalize(type_name.rsplit_once('.').map_or(type_name.as_str(), |(_, name)| name))
+        }
+        Array(inner_type) => format!("{}Array", type_argument_name(inner_type)),
+        FixedArray(inner_type, size) => format!("{}Array{}", type_argument_name(inner_type), size),
+        Set(inner_type) => format!("{}Set", type_argument_name(inner_type)),
+        Optional(inner_type) => format!("Optional{}", type_argument_name(inner_type)),
+        Map(key_type, value_type) => format!("{}{}Map", type_argument_name(key_type), type_argument_name(value_type)),
+        FieldType::Str => "String".to_string(),
+        _ => capitalize(field_type.to_string().as_str()),
+    }
+}
+
+impl SemanticResolver for GenericSemanticResolver {
+
+    fn resolve(&self, schema: &mut Schema, context: &Schema) -> MorphResult<()> {
+
+        let mut all_errors: Vec<Box<dyn MorphError>> = Vec::new();
+        let mut specializations = Specializations::default();
+
+        for packet in &mut schema.packets {
+            let owner = format!("{} '{}'", packet.kind, packet.name);
+            self.specialize_fields(&mut packet.fields, owner.as_str(), context, &mut specializations, &mut all_errors);
+        }
+
+        for union in &mut schema.unions {
+            let owner = format!("union '{}'", union.name);
+            self.specialize_fields(&mut union.variants, owner.as_str(), context, &mut specializations, &mut all_errors);
+        }
+
+        for alias in &mut schema.aliases {
+            match self.specialize_type(&alias.typ, context, &mut specializations, 0) {
+                Ok(typ) => alias.typ = typ,
+                Err(message) => {
+                    let err = SemanticError {
+                        message: format!("{} in type alias '{}'", message, alias.name),
+                    };
+                    all_errors.push(Box::new(err));
+                }
+            }
+        }
+
+        schema.packets.extend(specializations.packets);
+
+        if all_errors.is_empty() {
+            Success(())
+
+        } else {
+            Errors(all_errors)
+
+        }
+
+    }
+
+}
@@ -0,0 +1,5 @@
+pub mod composite_semantic_resolver;
+pub mod namespace_semantic_resolver;
+pub mod constant_semantic_resolver;
+pub mod alias_semantic_resolver;
+pub mod generic_semantic_resolver;
@@ -0,0 +1,144 @@
+use crate::core::semantics::semantic_resolver::SemanticResolver;
+use crate::core::FieldType::{Array, FixedArray, Generic, Map, Nested, Optional, Set, Union};
+use crate::core::{qualify_name, FieldType, Schema};
+use crate::utils::MorphResult::Success;
+use crate::utils::MorphResult;
+use std::collections::HashSet;
+
+pub struct NamespaceSemanticResolver;
+
+struct NamespaceScope {
+    declared: HashSet<String>,
+    unions: HashSet<String>,
+}
+
+// Looks the name up in the current namespace first and then in every enclosing one,
+// so 'Position' and 'world.Position' both resolve from inside 'game.player'.
+pub fn resolve_qualified_name(name: &str, namespace: &Option<String>, declared: &HashSet<String>) -> Option<String> {
+
+    let mut scope: Vec<&str> = match namespace {
+        Some(namespace) => namespace.split('.').collect(),
+        None => Vec::new(),
+    };
+
+    loop {
+
+        let scope_namespace = if scope.is_empty() { None } else { Some(scope.join(".")) };
+        let candidate = qualify_name(&scope_namespace, name);
+
+        if declared.contains(&candidate) {
+            return Some(candidate);
+        }
+
+        scope.pop()?;
+
+    }
+
+}
+
+impl NamespaceSemanticResolver {
+
+    fn resolve_type(&self, field_type: &mut FieldType, namespace: &Option<String>, type_params: &[String], scope: &NamespaceScope) {
+        match field_type {
+            Nested(type_name) if type_params.contains(type_name) => {}
+            Nested(type_name) => {
+                if let Some(qualified_name) = resolve_qualified_name(type_name, namespace, &scope.declared) {
+                    if scope.unions.contains(&qualified_name) {
+                        *field_type = Union(qualified_name);
+                    } else {
+                        *type_name = qualified_name;
+                    }
+                }
+            }
+            Generic(type_name, args) => {
+                if let Some(qualified_name) = resolve_qualified_name(type_name, namespace, &scope.declared) {
+                    *type_name = qualified_name;
+                }
+                for arg in args {
+                    self.resolve_type(arg, namespace, type_params, scope);
+                }
+            }
+            Array(inner_type) | FixedArray(inner_type, _) | Set(inner_type) | Optional(inner_type) => {
+                self.resolve_type(inner_type, namespace, type_params, scope)
+            }
+            Map(key_type, value_type) => {
+                self.resolve_type(key_type, namespace, type_params, scope);
+                self.resolve_type(value_type, namespace, type_params, scope);
+            }
+            _ => {}
+        }
+    }
+
+}
+
+impl SemanticResolver for NamespaceSemanticResolver {
+
+    fn resolve(&self, schema: &mut Schema, context: &Schema) -> MorphResult<()> {
+
+        let unions: HashSet<String> = context.unions.iter()
+            .map(|union| union.qualified_name())
+            .collect();
+
+        let declared: HashSet<String> = context.packets.iter()
+            .chain(context.templates.iter())
+            .map(|packet| packet.qualified_name())
+            .chain(context.enums.iter().map(|enum_decl| enum_decl.qualified_name()))
+            .chain(context.aliases.iter().map(|alias| alias.qualified_name()))
+            .chain(unions.iter().cloned())
+            .collect();
+
+        let scope = NamespaceScope { declared, unions };
+
+        for packet in schema.packets.iter_mut().chain(schema.templates.iter_mut()) {
+
+            if let Some(parent) = &packet.parent
+                && let Some(qualified_name) = resolve_qualified_name(parent, &packet.namespace, &scope.declared) {
+                packet.parent = Some(qualified_name);
+            }
+
+            for field in &mut packet.fields {
+                self.resolve_type(&mut field.typ, &packet.namespace, &packet.type_params, &scope);
+            }
+
+        }
+
+        for service in &mut schema.services {
+            for rpc in &mut service.methods {
+                if let Some(qualified_name) = resolve_qualified_name(&rpc.request, &service.namespace, &scope.declared) {
+                    rpc.request = qualified_name;
+                }
+                if let Some(qualified_name) = resolve_qualified_name(&rpc.response, &service.namespace, &scope.declared) {
+                    rpc.response = qualified_name;
+                }
+            }
+        }
+
+        for transition in &mut schema.transitions {
+            if let Some(qualified_name) = resolve_qualified_name(&transition.packet, &transition.namespace, &scope.declared) {
+                transition.packet = qualified_name;
+            }
+        }
+
+        for enum_decl in &mut schema.enums {
+            self.resolve_type(&mut enum_decl.typ, &enum_decl.namespace, &[], &scope);
+        }
+
+        for constant in &mut schema.constants {
+            self.resolve_type(&mut constant.typ, &constant.namespace, &[], &scope);
+        }
+
+        for alias in &mut schema.aliases {
+            self.resolve_type(&mut alias.typ, &alias.namespace, &[], &scope);
+        }
+
+        for union in &mut schema.unions {
+            for variant in &mut union.variants {
+                self.resolve_type(&mut variant.typ, &union.namespace, &[], &scope);
+            }
+        }
+
+        Success(())
+
+    }
+
+}
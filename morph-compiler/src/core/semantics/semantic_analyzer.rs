@@ -1,6 +1,6 @@
 use crate::utils::{MorphError, MorphResult};
 use std::fmt::{Display, Formatter};
-use crate::core::Packet;
+use crate::core::Schema;
 
 #[derive(Debug)]
 pub struct SemanticError {
@@ -19,7 +19,38 @@ impl MorphError for SemanticError {
     }
 }
 
+#[derive(Debug)]
+pub struct SemanticWarning {
+    pub message: String,
+}
+
+impl Display for SemanticWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Warning: {}", self.message)
+    }
+}
+
 pub trait SemanticAnalyzer {
-    fn analyze(&self, packets: &Vec<Packet>) -> MorphResult<()>;
-    
-}
\ No newline at end of file
+    fn analyze(&self, schema: &Schema, context: &Schema) -> MorphResult<()>;
+
+    fn warnings(&self, _schema: &Schema, _context: &Schema) -> Vec<SemanticWarning> {
+        Vec::new()
+    }
+
+}
+
+/// Declarations of the context which are not declared by the schema itself, i.e. the imported ones
+pub fn imported<'a, T>(context: &'a [T], schema: &[T], name: impl Fn(&T) -> String) -> Vec<&'a T> {
+
+    let mut own_names: Vec<String> = schema.iter().map(&name).collect();
+
+    context.iter()
+        .filter(|declaration| match own_names.iter().position(|own_name| *own_name == name(declaration)) {
+            Some(index) => {
+                own_names.remove(index);
+                false
+            }
+            None => true,
+        })
+        .collect()
+}
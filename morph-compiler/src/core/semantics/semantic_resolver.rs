@@ -0,0 +1,7 @@
+use crate::core::Schema;
+use crate::utils::MorphResult;
+
+pub trait SemanticResolver {
+    fn resolve(&self, schema: &mut Schema, context: &Schema) -> MorphResult<()>;
+
+}
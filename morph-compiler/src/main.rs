@@ -2,13 +2,14 @@ mod cli;
 mod core;
 mod utils;
 
+use std::collections::{HashSet, VecDeque};
 use std::fs;
 use crate::cli::{Cli, Commands, Lang};
 use crate::core::*;
 use std::path::{Path, PathBuf};
 use clap::Parser;
 use walkdir::WalkDir;
-use crate::utils::{print_morph_errors, FileWrapper, MorphResult};
+use crate::utils::{print_morph_errors, MorphResult};
 use crate::utils::MorphResult::{Errors, Success};
 
 fn main() {
@@ -65,6 +66,7 @@ no_args_constructor     = true
 generate_to_string      = true
 generate_equals         = true
 generate_hashcode       = true
+optional_getters        = false
 "#,
             output_dir.display()
         );
@@ -112,13 +114,47 @@ fn build(lang: &Lang, dir: &PathBuf) -> Result<(), std::io::Error> {
     let mut semantic_analyzer = CompositeSemanticAnalyzer::new();
     semantic_analyzer.add_analyzer(Box::new(NameSemanticAnalyzer));
     semantic_analyzer.add_analyzer(Box::new(IdSemanticAnalyzer));
+    semantic_analyzer.add_analyzer(Box::new(StateSemanticAnalyzer));
+    semantic_analyzer.add_analyzer(Box::new(EnumSemanticAnalyzer));
+    semantic_analyzer.add_analyzer(Box::new(UnionSemanticAnalyzer));
+    semantic_analyzer.add_analyzer(Box::new(ConstantSemanticAnalyzer));
+    semantic_analyzer.add_analyzer(Box::new(InheritanceSemanticAnalyzer));
     semantic_analyzer.add_analyzer(Box::new(DependencySemanticAnalyzer));
+    semantic_analyzer.add_analyzer(Box::new(ServiceSemanticAnalyzer));
     semantic_analyzer.add_analyzer(Box::new(FieldSemanticAnalyzer));
+    semantic_analyzer.add_analyzer(Box::new(ConstraintSemanticAnalyzer));
+    semantic_analyzer.add_analyzer(Box::new(AttributeSemanticAnalyzer));
+    semantic_analyzer.add_analyzer(Box::new(VersionSemanticAnalyzer));
+    semantic_analyzer.add_analyzer(Box::new(DefaultSemanticAnalyzer));
+
+    let mut semantic_resolver = CompositeSemanticResolver::new();
+    semantic_resolver.add_resolver(Box::new(NamespaceSemanticResolver));
+    semantic_resolver.add_resolver(Box::new(AliasSemanticResolver));
+    semantic_resolver.add_resolver(Box::new(GenericSemanticResolver));
+    semantic_resolver.add_resolver(Box::new(ConstantSemanticResolver));
+
+    let mut loader = SchemaLoader::new(&lexer, &parser);
+    let mut built_paths: HashSet<PathBuf> = HashSet::new();
+    let mut built_schema = Schema::default();
 
     let morph_file_paths = find_morph_files(dir);
     println!("Found {}x morph files", morph_file_paths.len());
 
-    for path in morph_file_paths {
+    let mut pending_paths: VecDeque<PathBuf> = morph_file_paths.into();
+
+    while let Some(path) = pending_paths.pop_front() {
+
+        let path = match SchemaLoader::canonicalize(&path) {
+            Success(path) => path,
+            Errors(errors) => {
+                print_morph_errors(&errors, "\t\t");
+                continue;
+            }
+        };
+
+        if !built_paths.insert(path.clone()) {
+            continue;
+        }
 
         let path_string = path.to_string_lossy();
 
@@ -127,17 +163,45 @@ fn build(lang: &Lang, dir: &PathBuf) -> Result<(), std::io::Error> {
             &lang,
             &config_path,
             &path,
-            &lexer,
-            &parser,
+            &mut loader,
+            &semantic_resolver,
             &semantic_analyzer,
         ) {
-            Success(()) => println!("- Successfully built '{}'", path_string),
+            Success(schema) => {
+                println!("- Successfully built '{}'", path_string);
+                built_schema.constants.extend(schema.constants);
+                for state in schema.states {
+                    if !built_schema.states.contains(&state) {
+                        built_schema.states.push(state);
+                    }
+                }
+                built_schema.transitions.extend(schema.transitions);
+                built_schema.packets.extend(schema.packets);
+            }
             Errors(errors) => {
                 println!("- Some errors occurred while building '{}'", path_string);
                 print_morph_errors(&errors, "\t\t")
             }
         }
 
+        pending_paths.extend(loader.imported_paths(&path));
+
+    }
+
+    if !built_schema.constants.is_empty() {
+        println!("- Building {}x constants", built_schema.constants.len());
+        if let Errors(errors) = generate_constants(lang, &config_path, &built_schema) {
+            println!("- Some errors occurred while building constants");
+            print_morph_errors(&errors, "\t\t")
+        }
+    }
+
+    if !built_schema.states.is_empty() {
+        println!("- Building {}x states", built_schema.states.len());
+        if let Errors(errors) = generate_states(lang, &config_path, &built_schema) {
+            println!("- Some errors occurred while building states");
+            print_morph_errors(&errors, "\t\t")
+        }
     }
 
     Ok(())
@@ -163,32 +227,33 @@ fn find_morph_files(dir: &Path) -> Vec<PathBuf> {
 fn build_file(
     lang: &Lang,
     config_path: &Path,
-    path: &PathBuf,
-    lexer: &SimpleLexer,
-    parser: &SimpleParser,
+    path: &Path,
+    loader: &mut SchemaLoader,
+    resolver: &CompositeSemanticResolver,
     analyzer: &CompositeSemanticAnalyzer
-) -> MorphResult<()> {
+) -> MorphResult<Schema> {
+
+    if let Errors(errors) = loader.load(path) {
+        return Errors(errors);
+    }
 
-    let mut wrapper = FileWrapper {
-        path: path.clone(),
+    let mut schema: Schema = match loader.schema(path) {
+        Some(schema) => schema.clone(),
+        None => Schema::default(),
     };
 
-    let tokens: Vec<Token>;
+    let mut context: Schema = loader.merged_schema(path);
 
-    match lexer.tokenize(&mut wrapper) {
-        Success(values) => tokens = values,
-        Errors(errors) => return Errors(errors),
+    if let Errors(errors) = resolver.resolve_context(&mut context) {
+        return Errors(errors);
     }
 
-    let packets: Vec<Packet>;
-
-    match parser.parse(&tokens) {
-        Success(values) => packets = values,
-        Errors(errors) => return Errors(errors),
+    if let Errors(errors) = resolver.resolve(&mut schema, &context) {
+        return Errors(errors);
     }
 
-    // println!("\nParsed {} packets:", packets.len());
-    // for packet in &packets {
+    // println!("\nParsed {} packets:", schema.packets.len());
+    // for packet in &schema.packets {
     //     if packet.is_auto {
     //         println!("  - {} (id: auto)", packet.name);
     //     } else {
@@ -199,14 +264,17 @@ fn build_file(
     //     }
     // }
 
-    match analyzer.analyze(&packets) {
-        Errors(errors) => return Errors(errors),
-        _ => {}
+    if let Errors(errors) = analyzer.analyze(&schema, &context) {
+        return Errors(errors);
+    }
+
+    for warning in analyzer.warnings(&schema, &context) {
+        println!("\t\t-> {}", warning);
     }
 
-    match generate(lang, &config_path, &packets) {
+    match generate(lang, config_path, &schema, &context) {
         Success(()) => {
-            Success(())
+            Success(schema)
         },
         Errors(errors) => Errors(errors),
     }
@@ -214,7 +282,34 @@ fn build_file(
 }
 
 
-fn generate(lang: &Lang, config_path: &Path, packets: &Vec<Packet>) -> MorphResult<()> {
+fn generate(lang: &Lang, config_path: &Path, schema: &Schema, context: &Schema) -> MorphResult<()> {
+
+    match create_generator(lang, config_path) {
+        Success(generator) => generator.generate(schema, context),
+        Errors(errors) => Errors(errors),
+    }
+
+}
+
+fn generate_constants(lang: &Lang, config_path: &Path, schema: &Schema) -> MorphResult<()> {
+
+    match create_generator(lang, config_path) {
+        Success(generator) => generator.generate_constants(schema),
+        Errors(errors) => Errors(errors),
+    }
+
+}
+
+fn generate_states(lang: &Lang, config_path: &Path, schema: &Schema) -> MorphResult<()> {
+
+    match create_generator(lang, config_path) {
+        Success(generator) => generator.generate_states(schema),
+        Errors(errors) => Errors(errors),
+    }
+
+}
+
+fn create_generator(lang: &Lang, config_path: &Path) -> MorphResult<Box<dyn Generator>> {
 
     match GenerationConfig::from_file(config_path) {
 
@@ -235,7 +330,7 @@ fn generate(lang: &Lang, config_path: &Path, packets: &Vec<Packet>) -> MorphResu
                 }
             };
 
-            generator.generate(packets)
+            Success(generator)
 
         }
 
@@ -248,6 +343,4 @@ fn generate(lang: &Lang, config_path: &Path, packets: &Vec<Packet>) -> MorphResu
 
     }
 
-
-
 }
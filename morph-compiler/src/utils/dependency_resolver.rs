@@ -173,7 +173,7 @@ impl<T: Dependent> DependencyGraph<T> {
         None
     }
 
-    fn find_cycle_from_node(&self, start_node_id: &str) -> Option<DependencyCycle> {
+    pub fn find_cycle_from_node(&self, start_node_id: &str) -> Option<DependencyCycle> {
         let mut visited = HashSet::new();
         let mut path = Vec::new();
 
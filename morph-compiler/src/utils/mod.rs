@@ -3,6 +3,8 @@ mod file_utils;
 mod error;
 pub mod dependency_resolver;
 mod hash_utils;
+#[cfg(test)]
+pub mod test_utils;
 
 pub use file_utils::*;
 pub use hash_utils::*;
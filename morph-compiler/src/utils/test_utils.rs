@@ -0,0 +1,23 @@
+use crate::core::{AstParser, Schema, SimpleLexer, SimpleParser};
+use crate::utils::MorphResult;
+use crate::utils::MorphResult::{Errors, Success};
+
+pub fn error_messages<T>(result: MorphResult<T>) -> Vec<String> {
+    match result {
+        Success(_) => Vec::new(),
+        Errors(errors) => errors.iter().map(|err| err.message()).collect(),
+    }
+}
+
+pub fn parse_schema(source: &str) -> Schema {
+
+    let tokens = match SimpleLexer.tokenize_str(source) {
+        Success(tokens) => tokens,
+        Errors(errors) => panic!("{:?}", error_messages::<()>(Errors(errors))),
+    };
+
+    match SimpleParser.parse(&tokens) {
+        Success(schema) => schema,
+        Errors(errors) => panic!("{:?}", error_messages::<()>(Errors(errors))),
+    }
+}
@@ -71,4 +71,11 @@ public final class BinaryReader {
         return new String(bytes, StandardCharsets.UTF_8);
     }
 
+    public static byte[] readBytes(InputStream in, int length) throws IOException {
+        if (length < 0) throw new IOException("Negative bytes length");
+        byte[] bytes = in.readNBytes(length);
+        if (bytes.length != length) throw new IOException("Unexpected EOF while reading bytes");
+        return bytes;
+    }
+
 }
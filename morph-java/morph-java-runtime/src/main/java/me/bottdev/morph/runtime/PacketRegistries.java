@@ -4,4 +4,8 @@ public class PacketRegistries {
 
     public static PacketRegistry DEFAULT = new PacketRegistry();
 
+    public static PacketRegistry CLIENTBOUND = new PacketRegistry();
+
+    public static PacketRegistry SERVERBOUND = new PacketRegistry();
+
 }
@@ -1,3 +1,4 @@
+import me.bottdev.morph.runtime.BinaryWriter;
 import me.bottdev.morph.runtime.MorphPacket;
 import me.bottdev.morph.runtime.PacketDecoder;
 import me.bottdev.morph.runtime.PacketEncoder;
@@ -11,7 +12,12 @@ import org.junit.jupiter.params.ParameterizedTest;
 import org.junit.jupiter.params.provider.ValueSource;
 import packets.*;
 
+import java.io.ByteArrayInputStream;
+import java.io.ByteArrayOutputStream;
+import java.io.IOException;
 import java.util.ArrayList;
+import java.util.Collections;
+import java.util.LinkedHashSet;
 import java.util.List;
 
 public class EncodeDecodeTests {
@@ -68,6 +74,7 @@ public class EncodeDecodeTests {
         playerData.setLang("en");
         playerData.setName("player1");
         playerData.setLastOnline(System.currentTimeMillis());
+        playerData.setGameMode(GameMode.CREATIVE);
 
         String initialStr = playerData.toString();
 
@@ -85,6 +92,42 @@ public class EncodeDecodeTests {
 
     }
 
+    @Test
+    public void testDefaultValues() {
+
+        PlayerData playerData = new PlayerData();
+        Assertions.assertEquals("en", playerData.getLang());
+        Assertions.assertEquals(Byte.valueOf(Constants.DEFAULT_VIEW_DISTANCE), playerData.getViewDistance());
+
+        Position position = new Position();
+        position.setWorld("world");
+
+        playerData.setName("player1");
+        playerData.setPosition(position);
+        playerData.setGameMode(GameMode.SURVIVAL);
+        playerData.setViewDistance(null);
+
+        byte[] data = packetEncoder.encode(playerData);
+        PlayerData decoded = (PlayerData) packetDecoder.decodeData(data);
+
+        Assertions.assertEquals(Byte.valueOf((byte) 8), decoded.getViewDistance());
+
+    }
+
+    @Test
+    public void testDecodeUnknownEnumValue() {
+
+        byte[] data = new byte[] {2};
+
+        IllegalArgumentException ex = Assertions.assertThrows(
+            IllegalArgumentException.class,
+            () -> GameMode.decode(new ByteArrayInputStream(data))
+        );
+
+        Assertions.assertEquals("Unknown value 2 for enum GameMode", ex.getMessage());
+
+    }
+
     @Test
     public void testEncodeDecodeParticles() {
 
@@ -122,6 +165,427 @@ public class EncodeDecodeTests {
 
     }
 
+    @Test
+    public void testStructIsNotRegistered() {
+
+        Assertions.assertFalse(MorphPacket.class.isAssignableFrom(ParticleOptions.class));
+
+        ParticleOptions options = new ParticleOptions();
+        options.setType("flame");
+        options.setSpeed(1.5f);
+
+        Particle particle = new Particle();
+        particle.setOptions(options);
+        particle.setCount(3);
+
+        byte[] data = packetEncoder.encode(particle);
+        MorphPacket decoded = packetDecoder.decodeData(data);
+
+        Assertions.assertEquals(particle, decoded);
+
+    }
+
+    @Test
+    public void testEncodeDecodeUnion() {
+
+        Position position = new Position();
+        position.setX(5f);
+        position.setWorld("world");
+
+        Interaction interaction = new Interaction();
+        interaction.setTarget(new InteractionTarget.Position(position));
+        interaction.setFallback(new InteractionTarget.Player("player1"));
+
+        byte[] data = packetEncoder.encode(interaction);
+        Interaction decoded = (Interaction) packetDecoder.decodeData(data);
+
+        Assertions.assertEquals(interaction, decoded);
+        Assertions.assertInstanceOf(InteractionTarget.Position.class, decoded.getTarget());
+        Assertions.assertEquals(InteractionTarget.Player.TAG, decoded.getFallback().getTag());
+
+    }
+
+    @Test
+    public void testEncodeDecodeFlags() {
+
+        Permissions permissions = Permissions.of(Permissions.READ, Permissions.ADMIN);
+
+        Assertions.assertTrue(permissions.has(Permissions.ADMIN));
+        Assertions.assertFalse(permissions.has(Permissions.WRITE));
+        Assertions.assertEquals(Permissions.READ, permissions.without(Permissions.ADMIN));
+        Assertions.assertEquals((byte) 0x81, permissions.getValue());
+
+        PermissionUpdate update = new PermissionUpdate("bott", permissions.with(Permissions.KICK));
+
+        byte[] data = packetEncoder.encode(update);
+        PermissionUpdate decoded = (PermissionUpdate) packetDecoder.decodeData(data);
+
+        Assertions.assertEquals(update, decoded);
+        Assertions.assertEquals(Permissions.READ, new PermissionUpdate().getPermissions());
+        Assertions.assertThrows(IllegalArgumentException.class, () -> Permissions.fromValue((byte) 0x10));
+
+    }
+
+    @Test
+    public void testEncodeDecodeSets() {
+
+        TeamUpdate update = new TeamUpdate();
+        update.getMembers().add("bott");
+        update.getMembers().add("alex");
+        update.getMembers().add("bott");
+        update.getAllowedModes().add(GameMode.CREATIVE);
+        update.setBannedIds(new LinkedHashSet<>(List.of(3, 7)));
+
+        Assertions.assertEquals(2, update.getMembers().size());
+
+        byte[] data = packetEncoder.encode(update);
+        TeamUpdate decoded = (TeamUpdate) packetDecoder.decodeData(data);
+
+        Assertions.assertEquals(update, decoded);
+        Assertions.assertEquals(List.of("bott", "alex"), new ArrayList<>(decoded.getMembers()));
+
+    }
+
+    @Test
+    public void testDecodeRejectsDuplicateSetElement() {
+
+        ByteArrayOutputStream out = new ByteArrayOutputStream();
+        BinaryWriter.writeInt(out, 2);
+        BinaryWriter.writeString(out, "bott");
+        BinaryWriter.writeString(out, "bott");
+        BinaryWriter.writeInt(out, 0);
+        BinaryWriter.writeBoolean(out, false);
+
+        IOException exception = Assertions.assertThrows(
+            IOException.class,
+            () -> TeamUpdate.decode(new ByteArrayInputStream(out.toByteArray()))
+        );
+
+        Assertions.assertInstanceOf(IllegalArgumentException.class, exception.getCause());
+
+    }
+
+    @Test
+    public void testDirectionRegistries() {
+
+        ChatMessage message = new ChatMessage("bott", "/spawn");
+        ChatCommand command = new ChatCommand("/spawn");
+
+        Assertions.assertEquals(message.getPacketId(), command.getPacketId());
+
+        PacketDecoder clientDecoder = new SimplePacketDecoder(PacketRegistries.CLIENTBOUND);
+        PacketDecoder serverDecoder = new SimplePacketDecoder(PacketRegistries.SERVERBOUND);
+
+        Assertions.assertEquals(message, clientDecoder.decodeData(packetEncoder.encode(message)));
+        Assertions.assertEquals(command, serverDecoder.decodeData(packetEncoder.encode(command)));
+        Assertions.assertTrue(PacketRegistries.DEFAULT.find(command.getPacketId()).isEmpty());
+
+    }
+
+    @Test
+    public void testProtocolStateMachine() {
+
+        ProtocolStateMachine client = new ProtocolStateMachine(ProtocolState.LOGIN, ProtocolState::getClientboundRegistry);
+        ProtocolStateMachine server = new ProtocolStateMachine(ProtocolState.LOGIN, ProtocolState::getServerboundRegistry);
+
+        LoginStart start = new LoginStart("bott");
+        LoginSuccess success = new LoginSuccess("bott", 42L);
+        KeepAlive keepAlive = new KeepAlive(1000L);
+
+        Assertions.assertEquals(start, server.decodeData(packetEncoder.encode(start)));
+        Assertions.assertEquals(ProtocolState.LOGIN, server.getState());
+
+        Assertions.assertEquals(success, client.decodeData(packetEncoder.encode(success)));
+        Assertions.assertEquals(ProtocolState.PLAY, client.getState());
+        Assertions.assertEquals(keepAlive, client.decodeData(packetEncoder.encode(keepAlive)));
+
+    }
+
+    @Test
+    public void testServiceRequestResponse() throws Exception {
+
+        List<byte[]> requests = new ArrayList<>();
+        ChatClient client = new ChatClient(requests::add);
+        ChatDispatcher dispatcher = new ChatDispatcher(new Chat() {
+
+            @Override
+            public ChatAck send(ChatCommand request) {
+                return new ChatAck(!request.getCommand().isEmpty(), request.getCommand().length());
+            }
+
+            @Override
+            public Position locate(PlayerData request) {
+                return request.getPosition();
+            }
+
+        });
+
+        var first = client.send(new ChatCommand("/spawn"));
+        var second = client.send(new ChatCommand(""));
+
+        client.receive(dispatcher.dispatch(requests.get(1)));
+        client.receive(dispatcher.dispatch(requests.get(0)));
+
+        Assertions.assertEquals(new ChatAck(true, 6), first.get());
+        Assertions.assertEquals(new ChatAck(false, 0), second.get());
+        Assertions.assertThrows(IOException.class, () -> client.receive(dispatcher.dispatch(requests.get(0))));
+
+    }
+
+    @Test
+    public void testVersionedEncodeDecode() throws IOException {
+
+        ClientSettings settings = new ClientSettings("en", true, (byte) 16, new ClientSkin("slim", (byte) 7));
+
+        ByteArrayOutputStream current = new ByteArrayOutputStream();
+        settings.encode(current, false);
+        Assertions.assertEquals(
+                new ClientSettings("en", false, (byte) 16, new ClientSkin("slim", (byte) 7)),
+                ClientSettings.decode(new ByteArrayInputStream(current.toByteArray()))
+        );
+
+        ByteArrayOutputStream legacy = new ByteArrayOutputStream();
+        settings.encode(legacy, false, 1);
+        ClientSettings decoded = ClientSettings.decode(new ByteArrayInputStream(legacy.toByteArray()), 1);
+
+        Assertions.assertTrue(decoded.isLegacyChat());
+        Assertions.assertEquals(10, decoded.getViewDistance());
+        Assertions.assertNull(decoded.getSkin());
+
+    }
+
+    @Test
+    public void testDecodeUnknownUnionTag() {
+
+        byte[] data = new byte[] {3};
+
+        Assertions.assertThrows(
+            java.io.IOException.class,
+            () -> InteractionTarget.decode(new ByteArrayInputStream(data))
+        );
+
+    }
+
+    @Test
+    public void testEncodeDecodeFixedArrays() {
+
+        Transform transform = new Transform();
+
+        for (int i = 0; i < 16; i++) {
+            transform.getChecksum().add((byte) i);
+        }
+
+        for (int row = 0; row < 3; row++) {
+            List<Float> rowList = new ArrayList<>();
+            for (int col = 0; col < 3; col++) {
+                rowList.add(row == col ? 1f : 0f);
+            }
+            transform.getRotation().add(rowList);
+        }
+
+        byte[] data = packetEncoder.encode(transform);
+
+        Assertions.assertEquals(1 + 16 + 9 * 4, data.length);
+
+        MorphPacket decoded = packetDecoder.decodeData(data);
+
+        Assertions.assertEquals(transform, decoded);
+
+    }
+
+    @Test
+    public void testFixedArraySizeIsValidated() {
+
+        Transform transform = new Transform();
+        transform.getChecksum().add((byte) 1);
+
+        Assertions.assertThrows(
+            IllegalArgumentException.class,
+            () -> packetEncoder.encode(transform)
+        );
+
+    }
+
+    @Test
+    public void testEncodeDecodeBytes() {
+
+        byte[] blob = new byte[4096];
+        for (int i = 0; i < blob.length; i++) {
+            blob[i] = (byte) (i * 31);
+        }
+
+        ChunkData chunk = new ChunkData();
+        chunk.setChunkX(4);
+        chunk.setChunkZ(-2);
+        chunk.setBlocks(blob);
+
+        byte[] data = packetEncoder.encode(chunk);
+
+        Assertions.assertEquals(1 + 4 + 4 + 4 + blob.length + 1, data.length);
+
+        ChunkData decoded = (ChunkData) packetDecoder.decodeData(data);
+
+        Assertions.assertArrayEquals(blob, decoded.getBlocks());
+        Assertions.assertNull(decoded.getLight());
+        Assertions.assertEquals(chunk, decoded);
+        Assertions.assertEquals(chunk.hashCode(), decoded.hashCode());
+
+    }
+
+    @Test
+    @SuppressWarnings("deprecation")
+    public void testEncodeDecodeTeleport() {
+
+        Position position = new Position();
+        position.setWorld("nether");
+
+        LegacyPosition legacyPosition = new LegacyPosition(7, -3);
+
+        Teleport teleport = new Teleport();
+        teleport.setTarget(position);
+        teleport.setLegacyTarget(legacyPosition);
+
+        byte[] data = packetEncoder.encode(teleport);
+        Teleport decoded = (Teleport) packetDecoder.decodeData(data);
+
+        Assertions.assertEquals(teleport, decoded);
+        Assertions.assertEquals(legacyPosition, decoded.getLegacyTarget());
+
+    }
+
+    @Test
+    public void testDeprecatedAttributes() throws NoSuchMethodException {
+
+        Assertions.assertTrue(LegacyPosition.class.isAnnotationPresent(Deprecated.class));
+        Assertions.assertTrue(Teleport.class.getMethod("getLegacyTarget").isAnnotationPresent(Deprecated.class));
+        Assertions.assertFalse(Teleport.class.getMethod("getTarget").isAnnotationPresent(Deprecated.class));
+
+    }
+
+    @Test
+    public void testEncodeDecodeInheritedFields() throws IOException {
+
+        PlayerMove move = new PlayerMove(42, 1.5, -0.25, true, "bott");
+
+        byte[] data = packetEncoder.encode(move);
+        PlayerMove decoded = (PlayerMove) packetDecoder.decodeData(data);
+
+        Assertions.assertEquals(move, decoded);
+        Assertions.assertEquals(42, decoded.getEntityId());
+
+        EntityMove header = EntityMove.decode(new ByteArrayInputStream(data, 1, data.length - 1));
+
+        Assertions.assertEquals(new EntityMove(42, 1.5, -0.25), header);
+
+    }
+
+    @Test
+    public void testEncodeDecodeGenericPages() {
+
+        PlayerStats stats = new PlayerStats("bott", 100, (byte) 20, "admin", List.of());
+        Leaderboard leaderboard = new Leaderboard(
+                new PagePlayerStats(1, List.of(stats)),
+                new PageString(2, List.of("bott", "xBott"))
+        );
+
+        byte[] data = packetEncoder.encode(leaderboard);
+        Leaderboard decoded = (Leaderboard) packetDecoder.decodeData(data);
+
+        Assertions.assertEquals(leaderboard, decoded);
+        Assertions.assertEquals(stats, decoded.getTop().getItems().get(0));
+        Assertions.assertThrows(IllegalArgumentException.class, () -> new PageString(0, new ArrayList<>(Collections.nCopies(17, "bott"))));
+
+    }
+
+    @Test
+    public void testEncodeDecodePlayerStats() {
+
+        PlayerStats stats = new PlayerStats("bott", 100, (byte) 200, null, List.of("admin", "builder"));
+
+        byte[] data = packetEncoder.encode(stats);
+        PlayerStats decoded = (PlayerStats) packetDecoder.decodeData(data);
+
+        Assertions.assertEquals(stats, decoded);
+
+    }
+
+    @Test
+    public void testConstraintsAreValidated() {
+
+        PlayerStats stats = new PlayerStats();
+
+        Assertions.assertThrows(IllegalArgumentException.class, () -> stats.setNickname("ab"));
+        Assertions.assertThrows(IllegalArgumentException.class, () -> stats.setNickname("a".repeat(17)));
+        Assertions.assertThrows(IllegalArgumentException.class, () -> stats.setHealth(-1));
+        Assertions.assertThrows(IllegalArgumentException.class, () -> stats.setHealth(101));
+        Assertions.assertThrows(IllegalArgumentException.class, () -> stats.setArmor((byte) 201));
+        Assertions.assertThrows(IllegalArgumentException.class, () -> stats.setTags(List.of("a", "b", "c", "d", "e")));
+        Assertions.assertThrows(
+            IllegalArgumentException.class,
+            () -> new PlayerStats("bott", 150, (byte) 0, null, new ArrayList<>())
+        );
+
+        stats.setTitle(null);
+        stats.setArmor((byte) 200);
+
+    }
+
+    @Test
+    public void testDecodeRejectsConstraintViolation() {
+
+        ByteArrayOutputStream out = new ByteArrayOutputStream();
+        BinaryWriter.writeString(out, "bott");
+        BinaryWriter.writeInt(out, 1000);
+        BinaryWriter.writeByte(out, (byte) 0);
+        BinaryWriter.writeBoolean(out, false);
+        BinaryWriter.writeInt(out, 0);
+
+        IOException exception = Assertions.assertThrows(
+            IOException.class,
+            () -> PlayerStats.decode(new ByteArrayInputStream(out.toByteArray()))
+        );
+
+        Assertions.assertInstanceOf(IllegalArgumentException.class, exception.getCause());
+
+    }
+
+    @Test
+    public void testEncodeDecodeScoreboard() {
+
+        Scoreboard scoreboard = new Scoreboard();
+        scoreboard.getScores().put("player1", 10);
+        scoreboard.getScores().put("player2", 25);
+
+        List<Position> checkpoints = new ArrayList<>();
+        for (int i = 0; i < 3; i++) {
+            Position position = new Position();
+            position.setX(i);
+            position.setY(i * 2f);
+            position.setZ(i * 3f);
+            position.setYaw(0f);
+            position.setPitch(0f);
+            position.setWorld("world");
+            checkpoints.add(position);
+        }
+        scoreboard.getCheckpoints().put("player1", checkpoints);
+
+        String initialStr = scoreboard.toString();
+
+        byte[] data = packetEncoder.encode(scoreboard);
+
+        Assertions.assertTrue(data.length > 0);
+
+        MorphPacket decoded = packetDecoder.decodeData(data);
+        String finalStr = decoded.toString();
+
+        Assertions.assertEquals(Scoreboard.class, decoded.getClass());
+        Assertions.assertEquals(initialStr, finalStr);
+        Assertions.assertEquals(scoreboard, decoded);
+
+        System.out.println(finalStr);
+
+    }
+
     @ParameterizedTest
     @ValueSource(ints = {3, 5, 7, 10, 20})
     public void testEncodeDecodeMatrix2D(int input) {
@@ -0,0 +1,9 @@
+package packets;
+
+public interface Chat {
+
+	ChatAck send(ChatCommand request);
+
+	Position locate(PlayerData request);
+
+}
\ No newline at end of file
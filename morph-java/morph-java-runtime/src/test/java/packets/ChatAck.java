@@ -0,0 +1,104 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+public final class ChatAck implements MorphPacket {
+
+	public static final byte PACKET_ID = 10;
+
+	private boolean delivered;
+	private int sequence;
+
+	public ChatAck(
+		boolean delivered,
+		int sequence
+	) {
+		this.delivered = delivered;
+		this.sequence = sequence;
+	}
+
+	public ChatAck() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public boolean isDelivered() {
+		return delivered;
+	}
+
+	public int getSequence() {
+		return sequence;
+	}
+
+	public void setDelivered(boolean value) {
+		this.delivered = value;
+	}
+
+	public void setSequence(int value) {
+		this.sequence = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeBoolean(out, delivered);
+
+		BinaryWriter.writeInt(out, sequence);
+
+	}
+	public static ChatAck decode(InputStream in) throws IOException {
+		try {
+			boolean delivered = BinaryReader.readBoolean(in);
+
+			int sequence = BinaryReader.readInt(in);
+
+			return new ChatAck(
+				delivered,
+				sequence
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode ChatAck", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, ChatAck::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "ChatAck{" +
+			"delivered=" + delivered +
+			", " + "sequence=" + sequence +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		ChatAck that = (ChatAck) o;
+		return delivered == that.delivered
+			&& sequence == that.sequence;
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(delivered, sequence);
+	}
+
+}
\ No newline at end of file
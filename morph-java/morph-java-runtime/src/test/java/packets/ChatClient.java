@@ -0,0 +1,77 @@
+package packets;
+
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.BinaryWriter;
+
+import java.io.ByteArrayInputStream;
+import java.io.ByteArrayOutputStream;
+import java.io.IOException;
+import java.io.InputStream;
+import java.util.Map;
+import java.util.concurrent.CompletableFuture;
+import java.util.concurrent.ConcurrentHashMap;
+import java.util.concurrent.atomic.AtomicInteger;
+import java.util.function.Consumer;
+
+public final class ChatClient {
+
+	@FunctionalInterface
+	private interface ResponseDecoder<T> {
+		T decode(InputStream in) throws IOException;
+	}
+
+	private record PendingCall<T>(CompletableFuture<T> future, ResponseDecoder<T> decoder) {
+
+		void complete(InputStream in) {
+			try {
+				future.complete(decoder.decode(in));
+			} catch (IOException e) {
+				future.completeExceptionally(e);
+			}
+		}
+
+	}
+
+	private final Consumer<byte[]> sender;
+	private final AtomicInteger nextRequestId = new AtomicInteger();
+	private final Map<Integer, PendingCall<?>> pending = new ConcurrentHashMap<>();
+
+	public ChatClient(Consumer<byte[]> sender) {
+		this.sender = sender;
+	}
+
+	public CompletableFuture<ChatAck> send(ChatCommand request) {
+		ByteArrayOutputStream out = new ByteArrayOutputStream();
+		request.encode(out, false);
+		return call((byte) 0, out.toByteArray(), ChatAck::decode);
+	}
+
+	public CompletableFuture<Position> locate(PlayerData request) {
+		ByteArrayOutputStream out = new ByteArrayOutputStream();
+		request.encode(out, false);
+		return call((byte) 1, out.toByteArray(), Position::decode);
+	}
+
+	public void receive(byte[] data) throws IOException {
+		InputStream in = new ByteArrayInputStream(data);
+		int requestId = BinaryReader.readInt(in);
+		PendingCall<?> call = pending.remove(requestId);
+		if (call == null) throw new IOException("Unknown request id " + requestId);
+		call.complete(in);
+	}
+
+	private <T> CompletableFuture<T> call(byte method, byte[] request, ResponseDecoder<T> decoder) {
+		int requestId = nextRequestId.getAndIncrement();
+		PendingCall<T> call = new PendingCall<>(new CompletableFuture<>(), decoder);
+		pending.put(requestId, call);
+
+		ByteArrayOutputStream out = new ByteArrayOutputStream();
+		BinaryWriter.writeInt(out, requestId);
+		BinaryWriter.writeByte(out, method);
+		BinaryWriter.writeBytes(out, request);
+		sender.accept(out.toByteArray());
+
+		return call.future();
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+public final class ChatCommand implements MorphPacket {
+
+	public static final byte PACKET_ID = (byte) 200;
+
+	private String command;
+
+	public ChatCommand(
+		String command
+	) {
+		this.command = command;
+	}
+
+	public ChatCommand() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public String getCommand() {
+		return command;
+	}
+
+	public void setCommand(String value) {
+		this.command = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeString(out, command);
+
+	}
+	public static ChatCommand decode(InputStream in) throws IOException {
+		try {
+			String command = BinaryReader.readString(in);
+
+			return new ChatCommand(
+				command
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode ChatCommand", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.SERVERBOUND.register(PACKET_ID, ChatCommand::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "ChatCommand{" +
+			"command=" + command +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		ChatCommand that = (ChatCommand) o;
+		return Objects.equals(command, that.command);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(command);
+	}
+
+}
\ No newline at end of file
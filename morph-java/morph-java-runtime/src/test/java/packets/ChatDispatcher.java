@@ -0,0 +1,36 @@
+package packets;
+
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.BinaryWriter;
+
+import java.io.ByteArrayInputStream;
+import java.io.ByteArrayOutputStream;
+import java.io.IOException;
+import java.io.InputStream;
+
+public final class ChatDispatcher {
+
+	private final Chat service;
+
+	public ChatDispatcher(Chat service) {
+		this.service = service;
+	}
+
+	public byte[] dispatch(byte[] data) throws IOException {
+		InputStream in = new ByteArrayInputStream(data);
+		int requestId = BinaryReader.readInt(in);
+		int method = Byte.toUnsignedInt(BinaryReader.readByte(in));
+
+		ByteArrayOutputStream out = new ByteArrayOutputStream();
+		BinaryWriter.writeInt(out, requestId);
+
+		switch (method) {
+			case 0 -> service.send(ChatCommand.decode(in)).encode(out, false);
+			case 1 -> service.locate(PlayerData.decode(in)).encode(out, false);
+			default -> throw new IOException("Unknown method " + method + " for service Chat");
+		}
+
+		return out.toByteArray();
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,104 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+public final class ChatMessage implements MorphPacket {
+
+	public static final byte PACKET_ID = (byte) 200;
+
+	private String sender;
+	private String text;
+
+	public ChatMessage(
+		String sender,
+		String text
+	) {
+		this.sender = sender;
+		this.text = text;
+	}
+
+	public ChatMessage() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public String getSender() {
+		return sender;
+	}
+
+	public String getText() {
+		return text;
+	}
+
+	public void setSender(String value) {
+		this.sender = value;
+	}
+
+	public void setText(String value) {
+		this.text = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeString(out, sender);
+
+		BinaryWriter.writeString(out, text);
+
+	}
+	public static ChatMessage decode(InputStream in) throws IOException {
+		try {
+			String sender = BinaryReader.readString(in);
+
+			String text = BinaryReader.readString(in);
+
+			return new ChatMessage(
+				sender,
+				text
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode ChatMessage", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.CLIENTBOUND.register(PACKET_ID, ChatMessage::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "ChatMessage{" +
+			"sender=" + sender +
+			", " + "text=" + text +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		ChatMessage that = (ChatMessage) o;
+		return Objects.equals(sender, that.sender)
+			&& Objects.equals(text, that.text);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(sender, text);
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,155 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+import java.util.Arrays;
+
+public final class ChunkData implements MorphPacket {
+
+	public static final byte PACKET_ID = 14;
+
+	private int chunkX;
+	private int chunkZ;
+	private byte[] blocks = new byte[0];
+	private byte[] light;
+
+	public ChunkData(
+		int chunkX,
+		int chunkZ,
+		byte[] blocks,
+		byte[] light
+	) {
+		this.chunkX = chunkX;
+		this.chunkZ = chunkZ;
+		this.blocks = blocks;
+		this.light = light;
+	}
+
+	public ChunkData() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public int getChunkX() {
+		return chunkX;
+	}
+
+	public int getChunkZ() {
+		return chunkZ;
+	}
+
+	public byte[] getBlocks() {
+		return blocks;
+	}
+
+	public byte[] getLight() {
+		return light;
+	}
+
+	public void setChunkX(int value) {
+		this.chunkX = value;
+	}
+
+	public void setChunkZ(int value) {
+		this.chunkZ = value;
+	}
+
+	public void setBlocks(byte[] value) {
+		this.blocks = value;
+	}
+
+	public void setLight(byte[] value) {
+		this.light = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeInt(out, chunkX);
+
+		BinaryWriter.writeInt(out, chunkZ);
+
+		BinaryWriter.writeInt(out, blocks.length);
+		BinaryWriter.writeBytes(out, blocks);
+
+		BinaryWriter.writeBoolean(out, light != null);
+		if (light != null) {
+
+			BinaryWriter.writeInt(out, light.length);
+			BinaryWriter.writeBytes(out, light);
+
+		}
+
+	}
+	public static ChunkData decode(InputStream in) throws IOException {
+		try {
+			int chunk_x = BinaryReader.readInt(in);
+
+			int chunk_z = BinaryReader.readInt(in);
+
+			int blocksLength = BinaryReader.readInt(in);
+			byte[] blocks = BinaryReader.readBytes(in, blocksLength);
+
+			byte[] light = null;
+			if (BinaryReader.readBoolean(in)) {
+				int lightValueLength = BinaryReader.readInt(in);
+				byte[] light_value = BinaryReader.readBytes(in, lightValueLength);
+
+				light = light_value;
+			}
+
+			return new ChunkData(
+				chunk_x,
+				chunk_z,
+				blocks,
+				light
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode ChunkData", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, ChunkData::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "ChunkData{" +
+			"chunkX=" + chunkX +
+			", " + "chunkZ=" + chunkZ +
+			", " + "blocks=" + Arrays.toString(blocks) +
+			", " + "light=" + Arrays.toString(light) +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		ChunkData that = (ChunkData) o;
+		return chunkX == that.chunkX
+			&& chunkZ == that.chunkZ
+			&& Arrays.equals(blocks, that.blocks)
+			&& Arrays.equals(light, that.light);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(chunkX, chunkZ, Arrays.hashCode(blocks), Arrays.hashCode(light));
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,209 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+/**
+ * Settings sent by the client after joining
+ */
+public final class ClientSettings implements MorphPacket {
+
+	public static final byte PACKET_ID = 120;
+	public static final int VERSION = 3;
+
+	private String locale;
+	private boolean legacyChat;
+	/**
+	 * @since 2
+	 */
+	private byte viewDistance;
+	/**
+	 * @since 3
+	 */
+	private ClientSkin skin;
+
+	public ClientSettings(
+		String locale,
+		boolean legacyChat,
+		byte viewDistance,
+		ClientSkin skin
+	) {
+		this.locale = locale;
+		this.legacyChat = legacyChat;
+		this.viewDistance = viewDistance;
+		this.skin = skin;
+	}
+
+	public ClientSettings() {
+		this.viewDistance = (byte) 10;
+	}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public String getLocale() {
+		return locale;
+	}
+
+	public boolean isLegacyChat() {
+		return legacyChat;
+	}
+
+	/**
+	 * @since 2
+	 */
+	public byte getViewDistance() {
+		return viewDistance;
+	}
+
+	/**
+	 * @since 3
+	 */
+	public ClientSkin getSkin() {
+		return skin;
+	}
+
+	public void setLocale(String value) {
+		this.locale = value;
+	}
+
+	public void setLegacyChat(boolean value) {
+		this.legacyChat = value;
+	}
+
+	/**
+	 * @since 2
+	 */
+	public void setViewDistance(byte value) {
+		this.viewDistance = value;
+	}
+
+	/**
+	 * @since 3
+	 */
+	public void setSkin(ClientSkin value) {
+		this.skin = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+		encode(out, encodeId, VERSION);
+	}
+
+	public void encode(ByteArrayOutputStream out, boolean encodeId, int protocolVersion) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeString(out, locale);
+
+		if (protocolVersion < 2) {
+
+			BinaryWriter.writeBoolean(out, legacyChat);
+
+		}
+
+		if (protocolVersion >= 2) {
+
+			BinaryWriter.writeByte(out, viewDistance);
+
+		}
+
+		if (protocolVersion >= 3) {
+
+			BinaryWriter.writeBoolean(out, skin != null);
+			if (skin != null) {
+
+				skin.encode(out, protocolVersion);
+
+			}
+
+		}
+
+	}
+	public static ClientSettings decode(InputStream in) throws IOException {
+		return decode(in, VERSION);
+	}
+
+	public static ClientSettings decode(InputStream in, int protocolVersion) throws IOException {
+		try {
+			String locale = BinaryReader.readString(in);
+
+			boolean legacy_chat = false;
+			if (protocolVersion < 2) {
+				boolean legacy_chat_value = BinaryReader.readBoolean(in);
+
+				legacy_chat = legacy_chat_value;
+			}
+
+			byte view_distance = (byte) 10;
+			if (protocolVersion >= 2) {
+				byte view_distance_value = BinaryReader.readByte(in);
+
+				view_distance = view_distance_value;
+			}
+
+			ClientSkin skin = null;
+			if (protocolVersion >= 3) {
+				ClientSkin skin_value = null;
+				if (BinaryReader.readBoolean(in)) {
+					ClientSkin skin_value_value = ClientSkin.decode(in, protocolVersion);
+
+					skin_value = skin_value_value;
+				}
+
+				skin = skin_value;
+			}
+
+			return new ClientSettings(
+				locale,
+				legacy_chat,
+				view_distance,
+				skin
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode ClientSettings", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, ClientSettings::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "ClientSettings{" +
+			"locale=" + locale +
+			", " + "legacyChat=" + legacyChat +
+			", " + "viewDistance=" + viewDistance +
+			", " + "skin=" + skin +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		ClientSettings that = (ClientSettings) o;
+		return Objects.equals(locale, that.locale)
+			&& legacyChat == that.legacyChat
+			&& viewDistance == that.viewDistance
+			&& Objects.equals(skin, that.skin);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(locale, legacyChat, viewDistance, skin);
+	}
+
+}
\ No newline at end of file
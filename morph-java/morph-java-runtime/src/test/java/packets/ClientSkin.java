@@ -0,0 +1,118 @@
+package packets;
+
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+/**
+ * @since 2
+ */
+public final class ClientSkin {
+	public static final int VERSION = 3;
+
+	private String model;
+	/**
+	 * @since 2
+	 */
+	private byte layers;
+
+	public ClientSkin(
+		String model,
+		byte layers
+	) {
+		this.model = model;
+		this.layers = layers;
+	}
+
+	public ClientSkin() {}
+
+	public String getModel() {
+		return model;
+	}
+
+	/**
+	 * @since 2
+	 */
+	public byte getLayers() {
+		return layers;
+	}
+
+	public void setModel(String value) {
+		this.model = value;
+	}
+
+	/**
+	 * @since 2
+	 */
+	public void setLayers(byte value) {
+		this.layers = value;
+	}
+
+	public void encode(ByteArrayOutputStream out) {
+		encode(out, VERSION);
+	}
+
+	public void encode(ByteArrayOutputStream out, int protocolVersion) {
+
+		BinaryWriter.writeString(out, model);
+
+		if (protocolVersion >= 2) {
+
+			BinaryWriter.writeByte(out, layers);
+
+		}
+
+	}
+	public static ClientSkin decode(InputStream in) throws IOException {
+		return decode(in, VERSION);
+	}
+
+	public static ClientSkin decode(InputStream in, int protocolVersion) throws IOException {
+		try {
+			String model = BinaryReader.readString(in);
+
+			byte layers = 0;
+			if (protocolVersion >= 2) {
+				byte layers_value = BinaryReader.readByte(in);
+
+				layers = layers_value;
+			}
+
+			return new ClientSkin(
+				model,
+				layers
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode ClientSkin", e);
+
+		}
+	}
+
+	@Override
+	public String toString() {
+		return "ClientSkin{" +
+			"model=" + model +
+			", " + "layers=" + layers +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		ClientSkin that = (ClientSkin) o;
+		return Objects.equals(model, that.model)
+			&& layers == that.layers;
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(model, layers);
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+package packets;
+
+public final class Constants {
+
+	public static final byte DEFAULT_VIEW_DISTANCE = (byte) 8;
+	public static final int MAX_NICKNAME_LENGTH = 16;
+
+	private Constants() {}
+
+}
\ No newline at end of file
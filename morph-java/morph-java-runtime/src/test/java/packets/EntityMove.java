@@ -0,0 +1,123 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+/**
+ * Header shared by all entity movement packets
+ */
+public final class EntityMove implements MorphPacket {
+
+	public static final byte PACKET_ID = 78;
+
+	private int entityId;
+	private double deltaX;
+	private double deltaZ;
+
+	public EntityMove(
+		int entityId,
+		double deltaX,
+		double deltaZ
+	) {
+		this.entityId = entityId;
+		this.deltaX = deltaX;
+		this.deltaZ = deltaZ;
+	}
+
+	public EntityMove() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public int getEntityId() {
+		return entityId;
+	}
+
+	public double getDeltaX() {
+		return deltaX;
+	}
+
+	public double getDeltaZ() {
+		return deltaZ;
+	}
+
+	public void setEntityId(int value) {
+		this.entityId = value;
+	}
+
+	public void setDeltaX(double value) {
+		this.deltaX = value;
+	}
+
+	public void setDeltaZ(double value) {
+		this.deltaZ = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeInt(out, entityId);
+
+		BinaryWriter.writeDouble(out, deltaX);
+
+		BinaryWriter.writeDouble(out, deltaZ);
+
+	}
+	public static EntityMove decode(InputStream in) throws IOException {
+		try {
+			int entity_id = BinaryReader.readInt(in);
+
+			double delta_x = BinaryReader.readDouble(in);
+			double delta_z = BinaryReader.readDouble(in);
+			return new EntityMove(
+				entity_id,
+				delta_x,
+				delta_z
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode EntityMove", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, EntityMove::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "EntityMove{" +
+			"entityId=" + entityId +
+			", " + "deltaX=" + deltaX +
+			", " + "deltaZ=" + deltaZ +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		EntityMove that = (EntityMove) o;
+		return entityId == that.entityId
+			&& deltaX == that.deltaX
+			&& deltaZ == that.deltaZ;
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(entityId, deltaX, deltaZ);
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+package packets;
+
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+
+public enum GameMode {
+
+	SURVIVAL((byte) 0),
+	CREATIVE((byte) 1),
+	SPECTATOR((byte) 3);
+
+	private final byte value;
+
+	GameMode(byte value) {
+		this.value = value;
+	}
+
+	public byte getValue() {
+		return value;
+	}
+
+	public static GameMode fromValue(byte value) {
+		for (GameMode variant : values()) {
+			if (variant.value == value) return variant;
+		}
+		throw new IllegalArgumentException("Unknown value " + value + " for enum GameMode");
+	}
+
+	public void encode(ByteArrayOutputStream out) {
+
+		BinaryWriter.writeByte(out, value);
+
+	}
+
+	public static GameMode decode(InputStream in) throws IOException {
+		byte value = BinaryReader.readByte(in);
+
+		return fromValue(value);
+	}
+
+}
\ No newline at end of file
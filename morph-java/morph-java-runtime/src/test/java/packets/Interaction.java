@@ -0,0 +1,114 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+public final class Interaction implements MorphPacket {
+
+	public static final byte PACKET_ID = 55;
+
+	private InteractionTarget target;
+	private InteractionTarget fallback;
+
+	public Interaction(
+		InteractionTarget target,
+		InteractionTarget fallback
+	) {
+		this.target = target;
+		this.fallback = fallback;
+	}
+
+	public Interaction() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public InteractionTarget getTarget() {
+		return target;
+	}
+
+	public InteractionTarget getFallback() {
+		return fallback;
+	}
+
+	public void setTarget(InteractionTarget value) {
+		this.target = value;
+	}
+
+	public void setFallback(InteractionTarget value) {
+		this.fallback = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		target.encode(out);
+
+		BinaryWriter.writeBoolean(out, fallback != null);
+		if (fallback != null) {
+
+			fallback.encode(out);
+
+		}
+
+	}
+	public static Interaction decode(InputStream in) throws IOException {
+		try {
+			InteractionTarget target = InteractionTarget.decode(in);
+
+			InteractionTarget fallback = null;
+			if (BinaryReader.readBoolean(in)) {
+				InteractionTarget fallback_value = InteractionTarget.decode(in);
+
+				fallback = fallback_value;
+			}
+
+			return new Interaction(
+				target,
+				fallback
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode Interaction", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, Interaction::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "Interaction{" +
+			"target=" + target +
+			", " + "fallback=" + fallback +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		Interaction that = (Interaction) o;
+		return Objects.equals(target, that.target)
+			&& Objects.equals(fallback, that.fallback);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(target, fallback);
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,192 @@
+package packets;
+
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+/**
+ * Target of an interaction
+ */
+public sealed interface InteractionTarget permits InteractionTarget.Player, InteractionTarget.Position, InteractionTarget.Particle {
+
+	byte getTag();
+
+	void encode(ByteArrayOutputStream out);
+
+	static InteractionTarget decode(InputStream in) throws IOException {
+		byte tag = BinaryReader.readByte(in);
+		return switch (tag) {
+			case Player.TAG -> Player.decode(in);
+			case Position.TAG -> Position.decode(in);
+			case Particle.TAG -> Particle.decode(in);
+			default -> throw new IOException("Unknown tag " + tag + " for union InteractionTarget");
+		};
+	}
+
+	final class Player implements InteractionTarget {
+
+		public static final byte TAG = 0;
+
+		private final String value;
+
+		public Player(String value) {
+			this.value = value;
+		}
+
+		public String getValue() {
+			return value;
+		}
+
+		@Override
+		public byte getTag() {
+			return TAG;
+		}
+
+		@Override
+		public void encode(ByteArrayOutputStream out) {
+
+			BinaryWriter.writeByte(out, TAG);
+
+			BinaryWriter.writeString(out, value);
+
+		}
+
+		static Player decode(InputStream in) throws IOException {
+			String value = BinaryReader.readString(in);
+
+			return new Player(value);
+		}
+
+		@Override
+		public String toString() {
+			return "InteractionTarget.Player{value=" + value + '}';
+		}
+
+		@Override
+		public boolean equals(Object o) {
+			if (this == o) return true;
+			if (o == null || getClass() != o.getClass()) return false;
+			Player that = (Player) o;
+			return Objects.equals(value, that.value);
+		}
+
+		@Override
+		public int hashCode() {
+			return Objects.hash(TAG, value);
+		}
+
+	}
+
+	final class Position implements InteractionTarget {
+
+		public static final byte TAG = 1;
+
+		private final packets.Position value;
+
+		public Position(packets.Position value) {
+			this.value = value;
+		}
+
+		public packets.Position getValue() {
+			return value;
+		}
+
+		@Override
+		public byte getTag() {
+			return TAG;
+		}
+
+		@Override
+		public void encode(ByteArrayOutputStream out) {
+
+			BinaryWriter.writeByte(out, TAG);
+
+			value.encode(out, false);
+
+		}
+
+		static Position decode(InputStream in) throws IOException {
+			packets.Position value = packets.Position.decode(in);
+
+			return new Position(value);
+		}
+
+		@Override
+		public String toString() {
+			return "InteractionTarget.Position{value=" + value + '}';
+		}
+
+		@Override
+		public boolean equals(Object o) {
+			if (this == o) return true;
+			if (o == null || getClass() != o.getClass()) return false;
+			Position that = (Position) o;
+			return Objects.equals(value, that.value);
+		}
+
+		@Override
+		public int hashCode() {
+			return Objects.hash(TAG, value);
+		}
+
+	}
+
+	final class Particle implements InteractionTarget {
+
+		public static final byte TAG = 2;
+
+		private final ParticleOptions value;
+
+		public Particle(ParticleOptions value) {
+			this.value = value;
+		}
+
+		public ParticleOptions getValue() {
+			return value;
+		}
+
+		@Override
+		public byte getTag() {
+			return TAG;
+		}
+
+		@Override
+		public void encode(ByteArrayOutputStream out) {
+
+			BinaryWriter.writeByte(out, TAG);
+
+			value.encode(out);
+
+		}
+
+		static Particle decode(InputStream in) throws IOException {
+			ParticleOptions value = ParticleOptions.decode(in);
+
+			return new Particle(value);
+		}
+
+		@Override
+		public String toString() {
+			return "InteractionTarget.Particle{value=" + value + '}';
+		}
+
+		@Override
+		public boolean equals(Object o) {
+			if (this == o) return true;
+			if (o == null || getClass() != o.getClass()) return false;
+			Particle that = (Particle) o;
+			return Objects.equals(value, that.value);
+		}
+
+		@Override
+		public int hashCode() {
+			return Objects.hash(TAG, value);
+		}
+
+	}
+
+}
\ No newline at end of file
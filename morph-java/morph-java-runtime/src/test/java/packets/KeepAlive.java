@@ -0,0 +1,81 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+public final class KeepAlive implements MorphPacket {
+
+	public static final byte PACKET_ID = 0;
+
+	private long time;
+
+	public KeepAlive(
+		long time
+	) {
+		this.time = time;
+	}
+
+	public KeepAlive() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public long getTime() {
+		return time;
+	}
+
+	public void setTime(long value) {
+		this.time = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeLong(out, time);
+
+	}
+	public static KeepAlive decode(InputStream in) throws IOException {
+		try {
+			long time = BinaryReader.readLong(in);
+
+			return new KeepAlive(
+				time
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode KeepAlive", e);
+
+		}
+	}
+
+	@Override
+	public String toString() {
+		return "KeepAlive{" +
+			"time=" + time +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		KeepAlive that = (KeepAlive) o;
+		return time == that.time;
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(time);
+	}
+
+}
\ No newline at end of file
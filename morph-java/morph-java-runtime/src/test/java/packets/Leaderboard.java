@@ -0,0 +1,122 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+public final class Leaderboard implements MorphPacket {
+
+	public static final byte PACKET_ID = 49;
+
+	/**
+	 * Morph type: {@code Page<PlayerStats>}
+	 */
+	private PagePlayerStats top;
+	/**
+	 * Morph type: {@code Page<str>}
+	 */
+	private PageString names;
+
+	public Leaderboard(
+		PagePlayerStats top,
+		PageString names
+	) {
+		this.top = top;
+		this.names = names;
+	}
+
+	public Leaderboard() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	/**
+	 * Morph type: {@code Page<PlayerStats>}
+	 */
+	public PagePlayerStats getTop() {
+		return top;
+	}
+
+	/**
+	 * Morph type: {@code Page<str>}
+	 */
+	public PageString getNames() {
+		return names;
+	}
+
+	/**
+	 * Morph type: {@code Page<PlayerStats>}
+	 */
+	public void setTop(PagePlayerStats value) {
+		this.top = value;
+	}
+
+	/**
+	 * Morph type: {@code Page<str>}
+	 */
+	public void setNames(PageString value) {
+		this.names = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		top.encode(out);
+
+		names.encode(out);
+
+	}
+	public static Leaderboard decode(InputStream in) throws IOException {
+		try {
+			PagePlayerStats top = PagePlayerStats.decode(in);
+
+			PageString names = PageString.decode(in);
+
+			return new Leaderboard(
+				top,
+				names
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode Leaderboard", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, Leaderboard::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "Leaderboard{" +
+			"top=" + top +
+			", " + "names=" + names +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		Leaderboard that = (Leaderboard) o;
+		return Objects.equals(top, that.top)
+			&& Objects.equals(names, that.names);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(top, names);
+	}
+
+}
\ No newline at end of file
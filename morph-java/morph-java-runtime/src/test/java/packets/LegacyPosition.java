@@ -0,0 +1,94 @@
+package packets;
+
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+/**
+ * Position format used before worlds were introduced
+ *
+ * @deprecated use Position
+ */
+@Deprecated
+public final class LegacyPosition {
+
+	private int legacyX;
+	private int legacyY;
+
+	public LegacyPosition(
+		int legacyX,
+		int legacyY
+	) {
+		this.legacyX = legacyX;
+		this.legacyY = legacyY;
+	}
+
+	public LegacyPosition() {}
+
+	public int getLegacyX() {
+		return legacyX;
+	}
+
+	public int getLegacyY() {
+		return legacyY;
+	}
+
+	public void setLegacyX(int value) {
+		this.legacyX = value;
+	}
+
+	public void setLegacyY(int value) {
+		this.legacyY = value;
+	}
+
+	public void encode(ByteArrayOutputStream out) {
+
+		BinaryWriter.writeInt(out, legacyX);
+
+		BinaryWriter.writeInt(out, legacyY);
+
+	}
+	public static LegacyPosition decode(InputStream in) throws IOException {
+		try {
+			int legacy_x = BinaryReader.readInt(in);
+
+			int legacy_y = BinaryReader.readInt(in);
+
+			return new LegacyPosition(
+				legacy_x,
+				legacy_y
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode LegacyPosition", e);
+
+		}
+	}
+
+	@Override
+	public String toString() {
+		return "LegacyPosition{" +
+			"legacyX=" + legacyX +
+			", " + "legacyY=" + legacyY +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		LegacyPosition that = (LegacyPosition) o;
+		return legacyX == that.legacyX
+			&& legacyY == that.legacyY;
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(legacyX, legacyY);
+	}
+
+}
\ No newline at end of file
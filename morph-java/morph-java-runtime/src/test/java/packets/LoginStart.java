@@ -0,0 +1,81 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+public final class LoginStart implements MorphPacket {
+
+	public static final byte PACKET_ID = 0;
+
+	private String name;
+
+	public LoginStart(
+		String name
+	) {
+		this.name = name;
+	}
+
+	public LoginStart() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public String getName() {
+		return name;
+	}
+
+	public void setName(String value) {
+		this.name = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeString(out, name);
+
+	}
+	public static LoginStart decode(InputStream in) throws IOException {
+		try {
+			String name = BinaryReader.readString(in);
+
+			return new LoginStart(
+				name
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode LoginStart", e);
+
+		}
+	}
+
+	@Override
+	public String toString() {
+		return "LoginStart{" +
+			"name=" + name +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		LoginStart that = (LoginStart) o;
+		return Objects.equals(name, that.name);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(name);
+	}
+
+}
\ No newline at end of file
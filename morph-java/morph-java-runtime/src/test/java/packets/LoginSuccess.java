@@ -0,0 +1,99 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+public final class LoginSuccess implements MorphPacket {
+
+	public static final byte PACKET_ID = 0;
+
+	private String name;
+	private long uuid;
+
+	public LoginSuccess(
+		String name,
+		long uuid
+	) {
+		this.name = name;
+		this.uuid = uuid;
+	}
+
+	public LoginSuccess() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public String getName() {
+		return name;
+	}
+
+	public long getUuid() {
+		return uuid;
+	}
+
+	public void setName(String value) {
+		this.name = value;
+	}
+
+	public void setUuid(long value) {
+		this.uuid = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeString(out, name);
+
+		BinaryWriter.writeLong(out, uuid);
+
+	}
+	public static LoginSuccess decode(InputStream in) throws IOException {
+		try {
+			String name = BinaryReader.readString(in);
+
+			long uuid = BinaryReader.readLong(in);
+
+			return new LoginSuccess(
+				name,
+				uuid
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode LoginSuccess", e);
+
+		}
+	}
+
+	@Override
+	public String toString() {
+		return "LoginSuccess{" +
+			"name=" + name +
+			", " + "uuid=" + uuid +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		LoginSuccess that = (LoginSuccess) o;
+		return Objects.equals(name, that.name)
+			&& uuid == that.uuid;
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(name, uuid);
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,112 @@
+package packets;
+
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+import java.util.List;
+import java.util.ArrayList;
+
+/**
+ * A page of results
+ */
+public final class PagePlayerStats {
+
+	private int total;
+	private List<PlayerStats> items = new ArrayList<>();
+
+	public PagePlayerStats(
+		int total,
+		List<PlayerStats> items
+	) {
+		validateItems(items);
+		this.total = total;
+		this.items = items;
+	}
+
+	public PagePlayerStats() {}
+
+	public int getTotal() {
+		return total;
+	}
+
+	public List<PlayerStats> getItems() {
+		return items;
+	}
+
+	public void setTotal(int value) {
+		this.total = value;
+	}
+
+	public void setItems(List<PlayerStats> value) {
+		validateItems(value);
+		this.items = value;
+	}
+
+	private static void validateItems(List<PlayerStats> value) {
+		if (value != null && value.size() > 16) throw new IllegalArgumentException("Field 'items' in PagePlayerStats must contain at most 16 items, actual count is " + value.size());
+	}
+
+	public void encode(ByteArrayOutputStream out) {
+
+		BinaryWriter.writeInt(out, total);
+
+		BinaryWriter.writeInt(out, items.size());
+		for (PlayerStats itemsItem : items) {
+
+			itemsItem.encode(out, false);
+
+		}
+
+	}
+	public static PagePlayerStats decode(InputStream in) throws IOException {
+		try {
+			int total = BinaryReader.readInt(in);
+
+			int itemsLength = BinaryReader.readInt(in);
+			ArrayList<PlayerStats> items = new ArrayList<>();
+			for (int itemsIndex = 0; itemsIndex < itemsLength; itemsIndex++) {
+				PlayerStats items_item = PlayerStats.decode(in);
+
+				items.add(items_item);
+			}
+
+			validateItems(items);
+
+			return new PagePlayerStats(
+				total,
+				items
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode PagePlayerStats", e);
+
+		}
+	}
+
+	@Override
+	public String toString() {
+		return "PagePlayerStats{" +
+			"total=" + total +
+			", " + "items=" + items +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		PagePlayerStats that = (PagePlayerStats) o;
+		return total == that.total
+			&& Objects.equals(items, that.items);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(total, items);
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,112 @@
+package packets;
+
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+import java.util.List;
+import java.util.ArrayList;
+
+/**
+ * A page of results
+ */
+public final class PageString {
+
+	private int total;
+	private List<String> items = new ArrayList<>();
+
+	public PageString(
+		int total,
+		List<String> items
+	) {
+		validateItems(items);
+		this.total = total;
+		this.items = items;
+	}
+
+	public PageString() {}
+
+	public int getTotal() {
+		return total;
+	}
+
+	public List<String> getItems() {
+		return items;
+	}
+
+	public void setTotal(int value) {
+		this.total = value;
+	}
+
+	public void setItems(List<String> value) {
+		validateItems(value);
+		this.items = value;
+	}
+
+	private static void validateItems(List<String> value) {
+		if (value != null && value.size() > 16) throw new IllegalArgumentException("Field 'items' in PageString must contain at most 16 items, actual count is " + value.size());
+	}
+
+	public void encode(ByteArrayOutputStream out) {
+
+		BinaryWriter.writeInt(out, total);
+
+		BinaryWriter.writeInt(out, items.size());
+		for (String itemsItem : items) {
+
+			BinaryWriter.writeString(out, itemsItem);
+
+		}
+
+	}
+	public static PageString decode(InputStream in) throws IOException {
+		try {
+			int total = BinaryReader.readInt(in);
+
+			int itemsLength = BinaryReader.readInt(in);
+			ArrayList<String> items = new ArrayList<>();
+			for (int itemsIndex = 0; itemsIndex < itemsLength; itemsIndex++) {
+				String items_item = BinaryReader.readString(in);
+
+				items.add(items_item);
+			}
+
+			validateItems(items);
+
+			return new PageString(
+				total,
+				items
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode PageString", e);
+
+		}
+	}
+
+	@Override
+	public String toString() {
+		return "PageString{" +
+			"total=" + total +
+			", " + "items=" + items +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		PageString that = (PageString) o;
+		return total == that.total
+			&& Objects.equals(items, that.items);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(total, items);
+	}
+
+}
\ No newline at end of file
@@ -53,7 +53,7 @@ public final class Particle implements MorphPacket {
 
 		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
 
-		options.encode(out, false);
+		options.encode(out);
 
 		BinaryWriter.writeInt(out, count);
 
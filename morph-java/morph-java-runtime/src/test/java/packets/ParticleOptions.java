@@ -1,18 +1,14 @@
 package packets;
 
-import me.bottdev.morph.runtime.MorphPacket;
 import me.bottdev.morph.runtime.BinaryWriter;
 import me.bottdev.morph.runtime.BinaryReader;
-import me.bottdev.morph.runtime.PacketRegistries;
 
 import java.io.ByteArrayOutputStream;
 import java.io.InputStream;
 import java.io.IOException;
 import java.util.Objects;
 
-public final class ParticleOptions implements MorphPacket {
-
-	public static final byte PACKET_ID = 117;
+public final class ParticleOptions {
 
 	private String type;
 	private float speed;
@@ -27,11 +23,6 @@ public final class ParticleOptions implements MorphPacket {
 
 	public ParticleOptions() {}
 
-	@Override
-	public byte getPacketId() {{
-		return PACKET_ID;
-	}}
-
 	public String getType() {
 		return type;
 	}
@@ -48,10 +39,7 @@ public final class ParticleOptions implements MorphPacket {
 		this.speed = value;
 	}
 
-	@Override
-	public void encode(ByteArrayOutputStream out, boolean encodeId) {
-
-		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+	public void encode(ByteArrayOutputStream out) {
 
 		BinaryWriter.writeString(out, type);
 
@@ -75,10 +63,6 @@ public final class ParticleOptions implements MorphPacket {
 		}
 	}
 
-	static {
-		PacketRegistries.DEFAULT.register(PACKET_ID, ParticleOptions::decode);
-	}
-
 	@Override
 	public String toString() {
 		return "ParticleOptions{" +
@@ -0,0 +1,106 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+public final class PermissionUpdate implements MorphPacket {
+
+	public static final byte PACKET_ID = 16;
+
+	private String player;
+	private Permissions permissions;
+
+	public PermissionUpdate(
+		String player,
+		Permissions permissions
+	) {
+		this.player = player;
+		this.permissions = permissions;
+	}
+
+	public PermissionUpdate() {
+		this.permissions = Permissions.READ;
+	}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public String getPlayer() {
+		return player;
+	}
+
+	public Permissions getPermissions() {
+		return permissions;
+	}
+
+	public void setPlayer(String value) {
+		this.player = value;
+	}
+
+	public void setPermissions(Permissions value) {
+		this.permissions = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeString(out, player);
+
+		permissions.encode(out);
+
+	}
+	public static PermissionUpdate decode(InputStream in) throws IOException {
+		try {
+			String player = BinaryReader.readString(in);
+
+			Permissions permissions = Permissions.decode(in);
+
+			return new PermissionUpdate(
+				player,
+				permissions
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode PermissionUpdate", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, PermissionUpdate::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "PermissionUpdate{" +
+			"player=" + player +
+			", " + "permissions=" + permissions +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		PermissionUpdate that = (PermissionUpdate) o;
+		return Objects.equals(player, that.player)
+			&& Objects.equals(permissions, that.permissions);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(player, permissions);
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+package packets;
+
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.ArrayList;
+import java.util.List;
+
+public final class Permissions {
+
+	public static final Permissions READ = new Permissions((byte) 0x1);
+	public static final Permissions WRITE = new Permissions((byte) 0x2);
+	public static final Permissions KICK = new Permissions((byte) 0x4);
+	/**
+	 * Grants every other permission
+	 */
+	public static final Permissions ADMIN = new Permissions((byte) 0x80);
+
+	private final byte value;
+
+	private Permissions(byte value) {
+		this.value = value;
+	}
+
+	public byte getValue() {
+		return value;
+	}
+
+	public static Permissions fromValue(byte value) {
+		if ((value & ~(byte) 0x87) != 0) {
+			throw new IllegalArgumentException("Unknown bits in value " + value + " for flags Permissions");
+		}
+		return new Permissions(value);
+	}
+
+	public static Permissions of(Permissions... flags) {
+		byte value = 0;
+		for (Permissions flag : flags) {
+			value = (byte) (value | flag.value);
+		}
+		return new Permissions(value);
+	}
+
+	public boolean has(Permissions flags) {
+		return (value & flags.value) == flags.value;
+	}
+
+	public Permissions with(Permissions flags) {
+		return new Permissions((byte) (value | flags.value));
+	}
+
+	public Permissions without(Permissions flags) {
+		return new Permissions((byte) (value & ~flags.value));
+	}
+
+	public boolean isEmpty() {
+		return value == 0;
+	}
+
+	public void encode(ByteArrayOutputStream out) {
+
+		BinaryWriter.writeByte(out, value);
+
+	}
+
+	public static Permissions decode(InputStream in) throws IOException {
+		byte value = BinaryReader.readByte(in);
+
+		return fromValue(value);
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		return value == ((Permissions) o).value;
+	}
+
+	@Override
+	public int hashCode() {
+		return Long.hashCode(value);
+	}
+
+	@Override
+	public String toString() {
+		List<String> names = new ArrayList<>();
+		if (has(READ)) names.add("READ");
+		if (has(WRITE)) names.add("WRITE");
+		if (has(KICK)) names.add("KICK");
+		if (has(ADMIN)) names.add("ADMIN");
+		return "Permissions" + names;
+	}
+
+}
\ No newline at end of file
@@ -16,25 +16,40 @@ public final class PlayerData implements MorphPacket {
 
 	private String name;
 	private String lang;
+	/**
+	 * Morph type: {@code Timestamp}
+	 */
 	private long lastOnline;
 	private Position position;
 	private boolean dead;
+	private GameMode gameMode;
+	private Position respawnPosition;
+	private Byte viewDistance;
 
 	public PlayerData(
 		String name,
 		String lang,
 		long lastOnline,
 		Position position,
-		boolean dead
+		boolean dead,
+		GameMode gameMode,
+		Position respawnPosition,
+		Byte viewDistance
 	) {
 		this.name = name;
 		this.lang = lang;
 		this.lastOnline = lastOnline;
 		this.position = position;
 		this.dead = dead;
+		this.gameMode = gameMode;
+		this.respawnPosition = respawnPosition;
+		this.viewDistance = viewDistance;
 	}
 
-	public PlayerData() {}
+	public PlayerData() {
+		this.lang = "en";
+		this.viewDistance = (byte) 8;
+	}
 
 	@Override
 	public byte getPacketId() {{
@@ -49,6 +64,9 @@ public final class PlayerData implements MorphPacket {
 		return lang;
 	}
 
+	/**
+	 * Morph type: {@code Timestamp}
+	 */
 	public long getLastOnline() {
 		return lastOnline;
 	}
@@ -61,6 +79,18 @@ public final class PlayerData implements MorphPacket {
 		return dead;
 	}
 
+	public GameMode getGameMode() {
+		return gameMode;
+	}
+
+	public Position getRespawnPosition() {
+		return respawnPosition;
+	}
+
+	public Byte getViewDistance() {
+		return viewDistance;
+	}
+
 	public void setName(String value) {
 		this.name = value;
 	}
@@ -69,6 +99,9 @@ public final class PlayerData implements MorphPacket {
 		this.lang = value;
 	}
 
+	/**
+	 * Morph type: {@code Timestamp}
+	 */
 	public void setLastOnline(long value) {
 		this.lastOnline = value;
 	}
@@ -81,6 +114,18 @@ public final class PlayerData implements MorphPacket {
 		this.dead = value;
 	}
 
+	public void setGameMode(GameMode value) {
+		this.gameMode = value;
+	}
+
+	public void setRespawnPosition(Position value) {
+		this.respawnPosition = value;
+	}
+
+	public void setViewDistance(Byte value) {
+		this.viewDistance = value;
+	}
+
 	@Override
 	public void encode(ByteArrayOutputStream out, boolean encodeId) {
 
@@ -96,6 +141,22 @@ public final class PlayerData implements MorphPacket {
 
 		BinaryWriter.writeBoolean(out, dead);
 
+		gameMode.encode(out);
+
+		BinaryWriter.writeBoolean(out, respawnPosition != null);
+		if (respawnPosition != null) {
+
+			respawnPosition.encode(out, false);
+
+		}
+
+		BinaryWriter.writeBoolean(out, viewDistance != null);
+		if (viewDistance != null) {
+
+			BinaryWriter.writeByte(out, viewDistance);
+
+		}
+
 	}
 	public static PlayerData decode(InputStream in) throws IOException {
 		try {
@@ -109,12 +170,33 @@ public final class PlayerData implements MorphPacket {
 
 			boolean dead = BinaryReader.readBoolean(in);
 
+			GameMode game_mode = GameMode.decode(in);
+
+			Position respawn_position = null;
+			if (BinaryReader.readBoolean(in)) {
+				Position respawn_position_value = Position.decode(in);
+
+				respawn_position = respawn_position_value;
+			}
+
+			Byte view_distance = null;
+			if (BinaryReader.readBoolean(in)) {
+				byte view_distance_value = BinaryReader.readByte(in);
+
+				view_distance = view_distance_value;
+			}
+
+			if (view_distance == null) view_distance = (byte) 8;
+
 			return new PlayerData(
 				name,
 				lang,
 				last_online,
 				position,
-				dead
+				dead,
+				game_mode,
+				respawn_position,
+				view_distance
 			);
 
 		} catch (Exception e) {
@@ -135,6 +217,9 @@ public final class PlayerData implements MorphPacket {
 			", " + "lastOnline=" + lastOnline +
 			", " + "position=" + position +
 			", " + "dead=" + dead +
+			", " + "gameMode=" + gameMode +
+			", " + "respawnPosition=" + respawnPosition +
+			", " + "viewDistance=" + viewDistance +
 		'}';
 	}
 
@@ -147,12 +232,15 @@ public final class PlayerData implements MorphPacket {
 			&& Objects.equals(lang, that.lang)
 			&& lastOnline == that.lastOnline
 			&& Objects.equals(position, that.position)
-			&& dead == that.dead;
+			&& dead == that.dead
+			&& Objects.equals(gameMode, that.gameMode)
+			&& Objects.equals(respawnPosition, that.respawnPosition)
+			&& Objects.equals(viewDistance, that.viewDistance);
 	}
 
 	@Override
 	public int hashCode() {
-		return Objects.hash(name, lang, lastOnline, position, dead);
+		return Objects.hash(name, lang, lastOnline, position, dead, gameMode, respawnPosition, viewDistance);
 	}
 
 }
\ No newline at end of file
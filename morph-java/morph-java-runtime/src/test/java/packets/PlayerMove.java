@@ -0,0 +1,164 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+public final class PlayerMove implements MorphPacket {
+
+	public static final byte PACKET_ID = 21;
+
+	private int entityId;
+	private double deltaX;
+	private double deltaZ;
+	private boolean sprinting;
+	private String name;
+
+	public PlayerMove(
+		int entityId,
+		double deltaX,
+		double deltaZ,
+		boolean sprinting,
+		String name
+	) {
+		validateName(name);
+		this.entityId = entityId;
+		this.deltaX = deltaX;
+		this.deltaZ = deltaZ;
+		this.sprinting = sprinting;
+		this.name = name;
+	}
+
+	public PlayerMove() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public int getEntityId() {
+		return entityId;
+	}
+
+	public double getDeltaX() {
+		return deltaX;
+	}
+
+	public double getDeltaZ() {
+		return deltaZ;
+	}
+
+	public boolean isSprinting() {
+		return sprinting;
+	}
+
+	public String getName() {
+		return name;
+	}
+
+	public void setEntityId(int value) {
+		this.entityId = value;
+	}
+
+	public void setDeltaX(double value) {
+		this.deltaX = value;
+	}
+
+	public void setDeltaZ(double value) {
+		this.deltaZ = value;
+	}
+
+	public void setSprinting(boolean value) {
+		this.sprinting = value;
+	}
+
+	public void setName(String value) {
+		validateName(value);
+		this.name = value;
+	}
+
+	private static void validateName(String value) {
+		if (value != null && value.length() > 16) throw new IllegalArgumentException("Field 'name' in PlayerMove must have a length of at most 16, actual length is " + value.length());
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeInt(out, entityId);
+
+		BinaryWriter.writeDouble(out, deltaX);
+
+		BinaryWriter.writeDouble(out, deltaZ);
+
+		BinaryWriter.writeBoolean(out, sprinting);
+
+		BinaryWriter.writeString(out, name);
+
+	}
+	public static PlayerMove decode(InputStream in) throws IOException {
+		try {
+			int entity_id = BinaryReader.readInt(in);
+
+			double delta_x = BinaryReader.readDouble(in);
+			double delta_z = BinaryReader.readDouble(in);
+			boolean sprinting = BinaryReader.readBoolean(in);
+
+			String name = BinaryReader.readString(in);
+
+			validateName(name);
+
+			return new PlayerMove(
+				entity_id,
+				delta_x,
+				delta_z,
+				sprinting,
+				name
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode PlayerMove", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, PlayerMove::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "PlayerMove{" +
+			"entityId=" + entityId +
+			", " + "deltaX=" + deltaX +
+			", " + "deltaZ=" + deltaZ +
+			", " + "sprinting=" + sprinting +
+			", " + "name=" + name +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		PlayerMove that = (PlayerMove) o;
+		return entityId == that.entityId
+			&& deltaX == that.deltaX
+			&& deltaZ == that.deltaZ
+			&& sprinting == that.sprinting
+			&& Objects.equals(name, that.name);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(entityId, deltaX, deltaZ, sprinting, name);
+	}
+
+}
\ No newline at end of file
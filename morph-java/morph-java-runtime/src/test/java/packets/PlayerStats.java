@@ -0,0 +1,223 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+import java.util.List;
+import java.util.ArrayList;
+
+public final class PlayerStats implements MorphPacket {
+
+	public static final byte PACKET_ID = 35;
+
+	private String nickname;
+	private int health;
+	private byte armor;
+	private String title;
+	private List<String> tags = new ArrayList<>();
+
+	public PlayerStats(
+		String nickname,
+		int health,
+		byte armor,
+		String title,
+		List<String> tags
+	) {
+		validateNickname(nickname);
+		validateHealth(health);
+		validateArmor(armor);
+		validateTitle(title);
+		validateTags(tags);
+		this.nickname = nickname;
+		this.health = health;
+		this.armor = armor;
+		this.title = title;
+		this.tags = tags;
+	}
+
+	public PlayerStats() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public String getNickname() {
+		return nickname;
+	}
+
+	public int getHealth() {
+		return health;
+	}
+
+	public byte getArmor() {
+		return armor;
+	}
+
+	public String getTitle() {
+		return title;
+	}
+
+	public List<String> getTags() {
+		return tags;
+	}
+
+	public void setNickname(String value) {
+		validateNickname(value);
+		this.nickname = value;
+	}
+
+	public void setHealth(int value) {
+		validateHealth(value);
+		this.health = value;
+	}
+
+	public void setArmor(byte value) {
+		validateArmor(value);
+		this.armor = value;
+	}
+
+	public void setTitle(String value) {
+		validateTitle(value);
+		this.title = value;
+	}
+
+	public void setTags(List<String> value) {
+		validateTags(value);
+		this.tags = value;
+	}
+
+	private static void validateNickname(String value) {
+		if (value != null && value.length() < 3) throw new IllegalArgumentException("Field 'nickname' in PlayerStats must have a length of at least 3, actual length is " + value.length());
+		if (value != null && value.length() > 16) throw new IllegalArgumentException("Field 'nickname' in PlayerStats must have a length of at most 16, actual length is " + value.length());
+	}
+
+	private static void validateHealth(int value) {
+		if (value < 0) throw new IllegalArgumentException("Field 'health' in PlayerStats must be at least 0, actual value is " + value);
+		if (value > 100) throw new IllegalArgumentException("Field 'health' in PlayerStats must be at most 100, actual value is " + value);
+	}
+
+	private static void validateArmor(byte value) {
+		if (Byte.toUnsignedInt(value) > 200) throw new IllegalArgumentException("Field 'armor' in PlayerStats must be at most 200, actual value is " + Byte.toUnsignedInt(value));
+	}
+
+	private static void validateTitle(String value) {
+		if (value != null && value.length() > 32) throw new IllegalArgumentException("Field 'title' in PlayerStats must have a length of at most 32, actual length is " + value.length());
+	}
+
+	private static void validateTags(List<String> value) {
+		if (value != null && value.size() > 4) throw new IllegalArgumentException("Field 'tags' in PlayerStats must contain at most 4 items, actual count is " + value.size());
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeString(out, nickname);
+
+		BinaryWriter.writeInt(out, health);
+
+		BinaryWriter.writeByte(out, armor);
+
+		BinaryWriter.writeBoolean(out, title != null);
+		if (title != null) {
+
+			BinaryWriter.writeString(out, title);
+
+		}
+
+		BinaryWriter.writeInt(out, tags.size());
+		for (String tagsItem : tags) {
+
+			BinaryWriter.writeString(out, tagsItem);
+
+		}
+
+	}
+	public static PlayerStats decode(InputStream in) throws IOException {
+		try {
+			String nickname = BinaryReader.readString(in);
+
+			validateNickname(nickname);
+
+			int health = BinaryReader.readInt(in);
+
+			validateHealth(health);
+
+			byte armor = BinaryReader.readByte(in);
+
+			validateArmor(armor);
+
+			String title = null;
+			if (BinaryReader.readBoolean(in)) {
+				String title_value = BinaryReader.readString(in);
+
+				title = title_value;
+			}
+
+			validateTitle(title);
+
+			int tagsLength = BinaryReader.readInt(in);
+			ArrayList<String> tags = new ArrayList<>();
+			for (int tagsIndex = 0; tagsIndex < tagsLength; tagsIndex++) {
+				String tags_item = BinaryReader.readString(in);
+
+				tags.add(tags_item);
+			}
+
+			validateTags(tags);
+
+			return new PlayerStats(
+				nickname,
+				health,
+				armor,
+				title,
+				tags
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode PlayerStats", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, PlayerStats::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "PlayerStats{" +
+			"nickname=" + nickname +
+			", " + "health=" + health +
+			", " + "armor=" + armor +
+			", " + "title=" + title +
+			", " + "tags=" + tags +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		PlayerStats that = (PlayerStats) o;
+		return Objects.equals(nickname, that.nickname)
+			&& health == that.health
+			&& armor == that.armor
+			&& Objects.equals(title, that.title)
+			&& Objects.equals(tags, that.tags);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(nickname, health, armor, title, tags);
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+package packets;
+
+import me.bottdev.morph.runtime.PacketRegistry;
+
+public enum ProtocolState {
+
+	LOGIN,
+	PLAY;
+
+	private final PacketRegistry registry = new PacketRegistry();
+	private final PacketRegistry clientboundRegistry = new PacketRegistry();
+	private final PacketRegistry serverboundRegistry = new PacketRegistry();
+
+	static {
+		LOGIN.serverboundRegistry.register(packets.LoginStart.PACKET_ID, packets.LoginStart::decode);
+		LOGIN.clientboundRegistry.register(packets.LoginSuccess.PACKET_ID, packets.LoginSuccess::decode);
+		PLAY.clientboundRegistry.register(packets.KeepAlive.PACKET_ID, packets.KeepAlive::decode);
+	}
+
+	public PacketRegistry getRegistry() {
+		return registry;
+	}
+
+	public PacketRegistry getClientboundRegistry() {
+		return clientboundRegistry;
+	}
+
+	public PacketRegistry getServerboundRegistry() {
+		return serverboundRegistry;
+	}
+
+}
\ No newline at end of file
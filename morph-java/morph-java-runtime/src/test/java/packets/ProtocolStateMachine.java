@@ -0,0 +1,51 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.PacketDecoder;
+import me.bottdev.morph.runtime.PacketRegistry;
+import me.bottdev.morph.runtime.decoders.SimplePacketDecoder;
+import me.bottdev.morph.runtime.exceptions.MorphDecodingException;
+
+import java.io.InputStream;
+import java.util.function.Function;
+
+public final class ProtocolStateMachine implements PacketDecoder {
+
+	private final Function<ProtocolState, PacketRegistry> registries;
+	private ProtocolState state;
+
+	public ProtocolStateMachine(ProtocolState state) {
+		this(state, ProtocolState::getRegistry);
+	}
+
+	public ProtocolStateMachine(ProtocolState state, Function<ProtocolState, PacketRegistry> registries) {
+		this.state = state;
+		this.registries = registries;
+	}
+
+	public ProtocolState getState() {
+		return state;
+	}
+
+	public void setState(ProtocolState state) {
+		this.state = state;
+	}
+
+	public PacketRegistry getRegistry() {
+		return registries.apply(state);
+	}
+
+	public void onPacket(MorphPacket packet) {
+		if (state == ProtocolState.LOGIN && packet.getClass() == packets.LoginSuccess.class) {
+			state = ProtocolState.PLAY;
+		}
+	}
+
+	@Override
+	public MorphPacket decodeStream(InputStream in) throws MorphDecodingException {
+		MorphPacket packet = new SimplePacketDecoder(getRegistry()).decodeStream(in);
+		onPacket(packet);
+		return packet;
+	}
+
+}
\ No newline at end of file
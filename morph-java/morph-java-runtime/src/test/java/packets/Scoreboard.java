@@ -0,0 +1,153 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+import java.util.List;
+import java.util.ArrayList;
+import java.util.Map;
+import java.util.LinkedHashMap;
+
+public final class Scoreboard implements MorphPacket {
+
+	public static final byte PACKET_ID = 116;
+
+	private Map<String, Integer> scores = new LinkedHashMap<>();
+	private Map<String, List<Position>> checkpoints = new LinkedHashMap<>();
+
+	public Scoreboard(
+		Map<String, Integer> scores,
+		Map<String, List<Position>> checkpoints
+	) {
+		this.scores = scores;
+		this.checkpoints = checkpoints;
+	}
+
+	public Scoreboard() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public Map<String, Integer> getScores() {
+		return scores;
+	}
+
+	public Map<String, List<Position>> getCheckpoints() {
+		return checkpoints;
+	}
+
+	public void setScores(Map<String, Integer> value) {
+		this.scores = value;
+	}
+
+	public void setCheckpoints(Map<String, List<Position>> value) {
+		this.checkpoints = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeInt(out, scores.size());
+		for (Map.Entry<String, Integer> scoresEntry : scores.entrySet()) {
+			String scoresKey = scoresEntry.getKey();
+			Integer scoresValue = scoresEntry.getValue();
+
+			BinaryWriter.writeString(out, scoresKey);
+
+			BinaryWriter.writeInt(out, scoresValue);
+
+		}
+
+		BinaryWriter.writeInt(out, checkpoints.size());
+		for (Map.Entry<String, List<Position>> checkpointsEntry : checkpoints.entrySet()) {
+			String checkpointsKey = checkpointsEntry.getKey();
+			List<Position> checkpointsValue = checkpointsEntry.getValue();
+
+			BinaryWriter.writeString(out, checkpointsKey);
+
+			BinaryWriter.writeInt(out, checkpointsValue.size());
+			for (Position checkpointsValueItem1 : checkpointsValue) {
+
+				checkpointsValueItem1.encode(out, false);
+
+			}
+
+		}
+
+	}
+	public static Scoreboard decode(InputStream in) throws IOException {
+		try {
+			int scoresLength = BinaryReader.readInt(in);
+			LinkedHashMap<String, Integer> scores = new LinkedHashMap<>();
+			for (int scoresIndex = 0; scoresIndex < scoresLength; scoresIndex++) {
+				String scores_key = BinaryReader.readString(in);
+
+				int scores_value = BinaryReader.readInt(in);
+
+				scores.put(scores_key, scores_value);
+			}
+
+			int checkpointsLength = BinaryReader.readInt(in);
+			LinkedHashMap<String, List<Position>> checkpoints = new LinkedHashMap<>();
+			for (int checkpointsIndex = 0; checkpointsIndex < checkpointsLength; checkpointsIndex++) {
+				String checkpoints_key = BinaryReader.readString(in);
+
+				int checkpointsValueLength = BinaryReader.readInt(in);
+				ArrayList<Position> checkpoints_value = new ArrayList<>();
+				for (int checkpointsValueIndex = 0; checkpointsValueIndex < checkpointsValueLength; checkpointsValueIndex++) {
+					Position checkpoints_value_item = Position.decode(in);
+
+					checkpoints_value.add(checkpoints_value_item);
+				}
+
+				checkpoints.put(checkpoints_key, checkpoints_value);
+			}
+
+			return new Scoreboard(
+				scores,
+				checkpoints
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode Scoreboard", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, Scoreboard::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "Scoreboard{" +
+			"scores=" + scores +
+			", " + "checkpoints=" + checkpoints +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		Scoreboard that = (Scoreboard) o;
+		return Objects.equals(scores, that.scores)
+			&& Objects.equals(checkpoints, that.checkpoints);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(scores, checkpoints);
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,175 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+import java.util.Set;
+import java.util.LinkedHashSet;
+
+public final class TeamUpdate implements MorphPacket {
+
+	public static final byte PACKET_ID = 84;
+
+	private Set<String> members = new LinkedHashSet<>();
+	private Set<GameMode> allowedModes = new LinkedHashSet<>();
+	private Set<Integer> bannedIds;
+
+	public TeamUpdate(
+		Set<String> members,
+		Set<GameMode> allowedModes,
+		Set<Integer> bannedIds
+	) {
+		validateMembers(members);
+		this.members = members;
+		this.allowedModes = allowedModes;
+		this.bannedIds = bannedIds;
+	}
+
+	public TeamUpdate() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public Set<String> getMembers() {
+		return members;
+	}
+
+	public Set<GameMode> getAllowedModes() {
+		return allowedModes;
+	}
+
+	public Set<Integer> getBannedIds() {
+		return bannedIds;
+	}
+
+	public void setMembers(Set<String> value) {
+		validateMembers(value);
+		this.members = value;
+	}
+
+	public void setAllowedModes(Set<GameMode> value) {
+		this.allowedModes = value;
+	}
+
+	public void setBannedIds(Set<Integer> value) {
+		this.bannedIds = value;
+	}
+
+	private static void validateMembers(Set<String> value) {
+		if (value != null && value.size() > 8) throw new IllegalArgumentException("Field 'members' in TeamUpdate must contain at most 8 items, actual count is " + value.size());
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		BinaryWriter.writeInt(out, members.size());
+		for (String membersItem : members) {
+
+			BinaryWriter.writeString(out, membersItem);
+
+		}
+
+		BinaryWriter.writeInt(out, allowedModes.size());
+		for (GameMode allowedModesItem : allowedModes) {
+
+			allowedModesItem.encode(out);
+
+		}
+
+		BinaryWriter.writeBoolean(out, bannedIds != null);
+		if (bannedIds != null) {
+
+			BinaryWriter.writeInt(out, bannedIds.size());
+			for (int bannedIdsItem1 : bannedIds) {
+
+				BinaryWriter.writeInt(out, bannedIdsItem1);
+
+			}
+
+		}
+
+	}
+	public static TeamUpdate decode(InputStream in) throws IOException {
+		try {
+			int membersLength = BinaryReader.readInt(in);
+			LinkedHashSet<String> members = new LinkedHashSet<>();
+			for (int membersIndex = 0; membersIndex < membersLength; membersIndex++) {
+				String members_item = BinaryReader.readString(in);
+
+				if (!members.add(members_item)) throw new IllegalArgumentException("Duplicate set element " + members_item);
+			}
+
+			validateMembers(members);
+
+			int allowedModesLength = BinaryReader.readInt(in);
+			LinkedHashSet<GameMode> allowed_modes = new LinkedHashSet<>();
+			for (int allowedModesIndex = 0; allowedModesIndex < allowedModesLength; allowedModesIndex++) {
+				GameMode allowed_modes_item = GameMode.decode(in);
+
+				if (!allowed_modes.add(allowed_modes_item)) throw new IllegalArgumentException("Duplicate set element " + allowed_modes_item);
+			}
+
+			Set<Integer> banned_ids = null;
+			if (BinaryReader.readBoolean(in)) {
+				int bannedIdsValueLength = BinaryReader.readInt(in);
+				LinkedHashSet<Integer> banned_ids_value = new LinkedHashSet<>();
+				for (int bannedIdsValueIndex = 0; bannedIdsValueIndex < bannedIdsValueLength; bannedIdsValueIndex++) {
+					int banned_ids_value_item = BinaryReader.readInt(in);
+
+					if (!banned_ids_value.add(banned_ids_value_item)) throw new IllegalArgumentException("Duplicate set element " + banned_ids_value_item);
+				}
+
+				banned_ids = banned_ids_value;
+			}
+
+			return new TeamUpdate(
+				members,
+				allowed_modes,
+				banned_ids
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode TeamUpdate", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, TeamUpdate::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "TeamUpdate{" +
+			"members=" + members +
+			", " + "allowedModes=" + allowedModes +
+			", " + "bannedIds=" + bannedIds +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		TeamUpdate that = (TeamUpdate) o;
+		return Objects.equals(members, that.members)
+			&& Objects.equals(allowedModes, that.allowedModes)
+			&& Objects.equals(bannedIds, that.bannedIds);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(members, allowedModes, bannedIds);
+	}
+
+}
\ No newline at end of file
@@ -0,0 +1,126 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+
+public final class Teleport implements MorphPacket {
+
+	public static final byte PACKET_ID = 102;
+
+	private Position target;
+	/**
+	 * @deprecated use teleport_target
+	 */
+	@Deprecated
+	private LegacyPosition legacyTarget;
+
+	public Teleport(
+		Position target,
+		LegacyPosition legacyTarget
+	) {
+		this.target = target;
+		this.legacyTarget = legacyTarget;
+	}
+
+	public Teleport() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public Position getTarget() {
+		return target;
+	}
+
+	/**
+	 * @deprecated use teleport_target
+	 */
+	@Deprecated
+	public LegacyPosition getLegacyTarget() {
+		return legacyTarget;
+	}
+
+	public void setTarget(Position value) {
+		this.target = value;
+	}
+
+	/**
+	 * @deprecated use teleport_target
+	 */
+	@Deprecated
+	public void setLegacyTarget(LegacyPosition value) {
+		this.legacyTarget = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		target.encode(out, false);
+
+		BinaryWriter.writeBoolean(out, legacyTarget != null);
+		if (legacyTarget != null) {
+
+			legacyTarget.encode(out);
+
+		}
+
+	}
+	public static Teleport decode(InputStream in) throws IOException {
+		try {
+			Position teleport_target = Position.decode(in);
+
+			LegacyPosition legacy_target = null;
+			if (BinaryReader.readBoolean(in)) {
+				LegacyPosition legacy_target_value = LegacyPosition.decode(in);
+
+				legacy_target = legacy_target_value;
+			}
+
+			return new Teleport(
+				teleport_target,
+				legacy_target
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode Teleport", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, Teleport::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "Teleport{" +
+			"target=" + target +
+			", " + "legacyTarget=" + legacyTarget +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		Teleport that = (Teleport) o;
+		return Objects.equals(target, that.target)
+			&& Objects.equals(legacyTarget, that.legacyTarget);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(target, legacyTarget);
+	}
+
+}
\ No newline at end of file
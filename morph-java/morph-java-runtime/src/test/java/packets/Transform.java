@@ -0,0 +1,136 @@
+package packets;
+
+import me.bottdev.morph.runtime.MorphPacket;
+import me.bottdev.morph.runtime.BinaryWriter;
+import me.bottdev.morph.runtime.BinaryReader;
+import me.bottdev.morph.runtime.PacketRegistries;
+
+import java.io.ByteArrayOutputStream;
+import java.io.InputStream;
+import java.io.IOException;
+import java.util.Objects;
+import java.util.List;
+import java.util.ArrayList;
+
+public final class Transform implements MorphPacket {
+
+	public static final byte PACKET_ID = 82;
+
+	private List<Byte> checksum = new ArrayList<>();
+	private List<List<Float>> rotation = new ArrayList<>();
+
+	public Transform(
+		List<Byte> checksum,
+		List<List<Float>> rotation
+	) {
+		this.checksum = checksum;
+		this.rotation = rotation;
+	}
+
+	public Transform() {}
+
+	@Override
+	public byte getPacketId() {{
+		return PACKET_ID;
+	}}
+
+	public List<Byte> getChecksum() {
+		return checksum;
+	}
+
+	public List<List<Float>> getRotation() {
+		return rotation;
+	}
+
+	public void setChecksum(List<Byte> value) {
+		this.checksum = value;
+	}
+
+	public void setRotation(List<List<Float>> value) {
+		this.rotation = value;
+	}
+
+	@Override
+	public void encode(ByteArrayOutputStream out, boolean encodeId) {
+
+		if (encodeId) BinaryWriter.writeByte(out, PACKET_ID);
+
+		if (checksum.size() != 16) throw new IllegalArgumentException("Array 'checksum' must contain exactly 16 elements, actual size is " + checksum.size());
+		for (byte checksumItem : checksum) {
+
+			BinaryWriter.writeByte(out, checksumItem);
+
+		}
+
+		if (rotation.size() != 3) throw new IllegalArgumentException("Array 'rotation' must contain exactly 3 elements, actual size is " + rotation.size());
+		for (List<Float> rotationItem : rotation) {
+
+			if (rotationItem.size() != 3) throw new IllegalArgumentException("Array 'rotationItem' must contain exactly 3 elements, actual size is " + rotationItem.size());
+			for (float rotationItemItem1 : rotationItem) {
+
+				BinaryWriter.writeFloat(out, rotationItemItem1);
+
+			}
+
+		}
+
+	}
+	public static Transform decode(InputStream in) throws IOException {
+		try {
+			ArrayList<Byte> checksum = new ArrayList<>();
+			for (int checksumIndex = 0; checksumIndex < 16; checksumIndex++) {
+				byte checksum_item = BinaryReader.readByte(in);
+
+				checksum.add(checksum_item);
+			}
+
+			ArrayList<List<Float>> rotation = new ArrayList<>();
+			for (int rotationIndex = 0; rotationIndex < 3; rotationIndex++) {
+				ArrayList<Float> rotation_item = new ArrayList<>();
+				for (int rotationItemIndex = 0; rotationItemIndex < 3; rotationItemIndex++) {
+					float rotation_item_item = BinaryReader.readFloat(in);
+
+					rotation_item.add(rotation_item_item);
+				}
+
+				rotation.add(rotation_item);
+			}
+
+			return new Transform(
+				checksum,
+				rotation
+			);
+
+		} catch (Exception e) {
+			throw new IOException("Failed to decode Transform", e);
+
+		}
+	}
+
+	static {
+		PacketRegistries.DEFAULT.register(PACKET_ID, Transform::decode);
+	}
+
+	@Override
+	public String toString() {
+		return "Transform{" +
+			"checksum=" + checksum +
+			", " + "rotation=" + rotation +
+		'}';
+	}
+
+	@Override
+	public boolean equals(Object o) {
+		if (this == o) return true;
+		if (o == null || getClass() != o.getClass()) return false;
+		Transform that = (Transform) o;
+		return Objects.equals(checksum, that.checksum)
+			&& Objects.equals(rotation, that.rotation);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(checksum, rotation);
+	}
+
+}
\ No newline at end of file